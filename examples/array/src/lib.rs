@@ -1,3 +1,6 @@
+use node_bindgen::core::NjError;
+use node_bindgen::sys::napi_value;
+use node_bindgen::core::val::{JsArray, JsEnv};
 use node_bindgen::derive::node_bindgen;
 
 /// create array and fill with increase value
@@ -15,3 +18,77 @@ fn make_array(count: i32) -> Vec<i32> {
 fn sum_array(array: Vec<i32>) -> i32 {
     array.iter().sum()
 }
+
+/// index of first negative value, remaining elements are not converted
+#[node_bindgen]
+fn first_negative(array: JsArray) -> Result<Option<u32>, NjError> {
+    for (i, value) in array.iter::<f64>()?.enumerate() {
+        if value? < 0.0 {
+            return Ok(Some(i as u32));
+        }
+    }
+    Ok(None)
+}
+
+/// double numbers in place
+#[node_bindgen]
+fn double_in_place(mut array: JsArray) -> Result<(), NjError> {
+    for i in 0..array.len()? {
+        if let Some(value) = array.get::<f64>(i)? {
+            array.set(i, value * 2.0)?;
+        }
+    }
+    Ok(())
+}
+
+/// append values and return new length
+#[node_bindgen]
+fn append(mut array: JsArray, values: Vec<String>) -> Result<u32, NjError> {
+    for value in values {
+        array.push(value)?;
+    }
+    array.len()
+}
+
+/// delete element and report whether it exists afterward
+#[node_bindgen]
+fn punch_hole(mut array: JsArray, index: u32) -> Result<(bool, bool), NjError> {
+    let deleted = array.delete_element(index)?;
+    Ok((deleted, array.has_element(index)?))
+}
+
+/// create array of squares
+#[node_bindgen]
+fn squares(count: u32, env: JsEnv) -> Result<JsArray, NjError> {
+    let mut array = JsArray::create(&env)?;
+    for i in 0..count {
+        array.push(i * i)?;
+    }
+    Ok(array)
+}
+
+/// build array of arrays, temporary values of each row are released as soon as row is stored
+#[node_bindgen]
+fn triangle(count: u32, env: JsEnv) -> Result<napi_value, NjError> {
+    let scope = env.open_escapable_handle_scope()?;
+    let rows = env.create_array_with_len(count as usize)?;
+    for i in 0..count {
+        let _row_scope = env.open_handle_scope()?;
+        let row = vec![i; i as usize].try_to_js(&env)?;
+        env.set_element(rows, row, i as usize)?;
+    }
+    scope.escape(rows)
+}
+
+/// parse numbers, message of first invalid value is thrown
+#[node_bindgen]
+fn parse_numbers(values: Vec<String>) -> Vec<Result<u32, String>> {
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|_| format!("invalid number: {value}"))
+        })
+        .collect()
+}
@@ -17,3 +17,41 @@ assert.equal(addon.sumArray([1,2,3]),6,"sum should be 6");
 assert.throws( () => addon.sumArray({ x: 2}),{
     message: 'Provided value was not an array as expected'
 });
+
+assert.strictEqual(addon.firstNegative([1, 2, -3, "not a number"]), 2);
+assert.strictEqual(addon.firstNegative([]), null);
+assert.throws(() => addon.firstNegative([1, "x", -1]), {
+    message: 'invalid type, expected: number, actual: string'
+});
+assert.throws(() => addon.firstNegative({ length: 1 }), {
+    message: 'invalid type, expected: array, actual: other'
+});
+
+let values = [1, 2.5, , 4];
+addon.doubleInPlace(values);
+assert.deepStrictEqual(values, [2, 5, , 8]);
+assert.ok(!(2 in values));
+
+let names = ["a"];
+assert.strictEqual(addon.append(names, ["b", "c"]), 3);
+assert.deepStrictEqual(names, ["a", "b", "c"]);
+
+let holes = [1, 2, 3];
+assert.deepStrictEqual(addon.punchHole(holes, 1), [true, false]);
+assert.strictEqual(holes.length, 3);
+assert.ok(!(1 in holes));
+assert.deepStrictEqual(addon.punchHole(holes, 10), [true, false]);
+assert.deepStrictEqual(addon.punchHole(Object.freeze([1]), 0), [false, true]);
+
+assert.deepStrictEqual(addon.squares(4), [0, 1, 4, 9]);
+assert.deepStrictEqual(addon.squares(0), []);
+
+assert.deepStrictEqual(addon.triangle(4), [[], [1], [2, 2], [3, 3, 3]]);
+assert.strictEqual(addon.triangle(2000)[1999].length, 1999);
+assert.strictEqual(addon.makeArray(200000)[199999], 199999);
+
+let numbers = Array.from({ length: 600 }, (_, i) => String(i));
+assert.strictEqual(addon.parseNumbers(numbers)[599], 599);
+assert.throws(() => addon.parseNumbers(["x"]), (err) => err === "invalid number: x");
+numbers[300] = "y";
+assert.throws(() => addon.parseNumbers(numbers), (err) => err === "invalid number: y");
@@ -28,3 +28,35 @@ fn return_u64(arg: u32) -> u64 {
     println!("bigint arg: {arg}");
     arg as u64
 }
+
+// u64 can be read from BigInt or safe integer number without losing precision.
+#[node_bindgen]
+fn increment_u64(arg: u64) -> u64 {
+    arg + 1
+}
+
+// i64 is returned as number, results beyond safe integer range are rejected.
+#[node_bindgen]
+fn double_i64(arg: i64) -> i64 {
+    arg.wrapping_mul(2)
+}
+
+#[node_bindgen]
+fn double_i64_big(arg: i64) -> BigInt {
+    BigInt::from(arg.wrapping_mul(2))
+}
+
+#[node_bindgen]
+fn negate_i128(arg: i128) -> i128 {
+    -arg
+}
+
+#[node_bindgen]
+fn square_u128(arg: u64) -> u128 {
+    arg as u128 * arg as u128
+}
+
+#[node_bindgen]
+fn usize_len(arg: usize) -> usize {
+    arg
+}
@@ -20,3 +20,60 @@ assert.equal(addon.multiplyBigInt(BigInt(5)), BigInt(10));
 
 // Rust's u64s turn into JS BigInts.
 assert.equal(addon.returnU64(5), BigInt(5));
+
+// Lossless 64 and 128 bit integers
+assert.strictEqual(addon.incrementU64(2n ** 64n - 2n), 2n ** 64n - 1n);
+assert.strictEqual(addon.incrementU64(41), 42n);
+assert.strictEqual(addon.incrementU64(Number.MAX_SAFE_INTEGER), 2n ** 53n);
+assert.throws(() => addon.incrementU64(2n ** 64n), {
+    name: 'RangeError',
+    message: 'value 18446744073709551616 out of range for u64'
+});
+assert.throws(() => addon.incrementU64(-1n), {
+    name: 'RangeError',
+    message: 'value -1 out of range for u64'
+});
+assert.throws(() => addon.incrementU64(-1), {
+    name: 'RangeError',
+    message: 'value -1 out of range for u64'
+});
+assert.throws(() => addon.incrementU64(2 ** 53), {
+    name: 'RangeError',
+    message: 'value 9007199254740992 out of range for safe integer'
+});
+assert.throws(() => addon.incrementU64(1.5), {
+    name: 'TypeError',
+    message: 'invalid type, expected: integer, actual: number 1.5'
+});
+assert.throws(() => addon.incrementU64(NaN), {
+    message: 'invalid type, expected: integer, actual: number NaN'
+});
+assert.throws(() => addon.incrementU64("1"), {
+    message: 'invalid type, expected: number or big_int, actual: string'
+});
+
+assert.strictEqual(addon.doubleI64(-21), -42);
+assert.strictEqual(addon.doubleI64(2 ** 51), 2 ** 52);
+assert.strictEqual(addon.doubleI64(2n ** 50n), 2 ** 51);
+assert.throws(() => addon.doubleI64(2 ** 52), {
+    name: 'RangeError',
+    message: 'value 9007199254740992 out of range for safe integer'
+});
+assert.strictEqual(addon.doubleI64Big(2 ** 52), 2n ** 53n);
+assert.strictEqual(addon.doubleI64Big(-(2n ** 62n)), -(2n ** 63n));
+assert.throws(() => addon.doubleI64(2n ** 63n), {
+    name: 'RangeError',
+    message: 'value 9223372036854775808 out of range for i64'
+});
+
+assert.strictEqual(addon.negateI128(2n ** 100n), -(2n ** 100n));
+assert.strictEqual(addon.negateI128(-5), 5n);
+assert.strictEqual(addon.negateI128(0n), 0n);
+assert.throws(() => addon.negateI128(2n ** 127n), {
+    name: 'RangeError',
+    message: 'value 170141183460469231731687303715884105728 out of range for i128'
+});
+
+assert.strictEqual(addon.squareU128(2n ** 64n - 1n), (2n ** 64n - 1n) ** 2n);
+
+assert.strictEqual(addon.usizeLen(7), 7n);
@@ -77,3 +77,68 @@ fn test4(first: JSArrayBuffer, second: JSArrayBuffer) -> Result<String, NjError>
 
     Ok(format!("{message1} {message2}"))
 }
+
+use node_bindgen::core::buffer::JsDataView;
+
+/// read message from window of DataView
+#[node_bindgen]
+fn test5(view: JsDataView) -> Result<String, NjError> {
+    let message = String::from_utf8(view.to_vec())?;
+    Ok(format!("reply {message} at {}", view.byte_offset()))
+}
+
+/// create DataView over part of rust buffer
+#[node_bindgen]
+fn test6(env: JsEnv) -> Result<JsDataView, NjError> {
+    JsDataView::from_array_buffer(&env, ArrayBuffer::new(b"skip:hello".to_vec()), 5, 5)
+}
+
+use node_bindgen::core::buffer::NodeBuffer;
+
+/// return Node Buffer without copying
+#[node_bindgen]
+fn test7(len: u32) -> NodeBuffer {
+    NodeBuffer::new((0..len).map(|i| (i % 256) as u8).collect())
+}
+
+use node_bindgen::core::buffer::JSArrayBufferMut;
+
+/// fill caller provided buffer, returns number of bytes written
+#[node_bindgen]
+fn read_into(buf: &mut [u8], value: u8) -> u32 {
+    buf.fill(value);
+    buf.len() as u32
+}
+
+/// swap content of two buffers of same length
+#[node_bindgen]
+fn swap(first: &mut [u8], second: &mut [u8]) -> Result<(), NjError> {
+    if first.len() != second.len() {
+        return Err(NjError::Other("buffers must have same length".to_owned()));
+    }
+    first.swap_with_slice(second);
+    Ok(())
+}
+
+/// copy source into start of destination, returns number of bytes copied
+#[node_bindgen]
+fn copy_into(dest: &mut [u8], src: &[u8]) -> u32 {
+    let len = dest.len().min(src.len());
+    dest[..len].copy_from_slice(&src[..len]);
+    len as u32
+}
+
+/// same as `copy_into` with source passed first
+#[node_bindgen]
+fn copy_from(src: JSArrayBuffer, dest: &mut [u8]) -> u32 {
+    let len = dest.len().min(src.len());
+    dest[..len].copy_from_slice(&src[..len]);
+    len as u32
+}
+
+/// upper case ascii in place and return same buffer
+#[node_bindgen]
+fn upper_in_place(mut data: JSArrayBufferMut) -> JSArrayBufferMut {
+    data.make_ascii_uppercase();
+    data
+}
@@ -18,4 +18,83 @@ assert.deepStrictEqual(JSON.parse(Buffer.from(record.buffer)), { a: 'b', b: 10 }
 
 
 assert.strictEqual(addon.test3(Buffer.from("hello")),"reply hello");
-assert.strictEqual(addon.test4(Buffer.from("hello"),Buffer.from("world")),"hello world");
\ No newline at end of file
+assert.strictEqual(addon.test4(Buffer.from("hello"),Buffer.from("world")),"hello world");
+
+let payload = Buffer.from("xxhello");
+let view = new DataView(payload.buffer, payload.byteOffset + 2, 5);
+assert.strictEqual(addon.test5(view), `reply hello at ${payload.byteOffset + 2}`);
+assert.throws(() => addon.test5(payload), {
+    message: 'invalid type, expected: DataView, actual: other'
+});
+
+let created = addon.test6();
+assert.ok(created instanceof DataView);
+assert.strictEqual(created.byteOffset, 5);
+assert.strictEqual(created.byteLength, 5);
+assert.strictEqual(Buffer.from(created.buffer, created.byteOffset, created.byteLength).toString(), "hello");
+let node_buffer = addon.test7(4 * 1024 * 1024);
+assert.ok(Buffer.isBuffer(node_buffer));
+assert.strictEqual(node_buffer.length, 4 * 1024 * 1024);
+assert.strictEqual(node_buffer[255], 255);
+assert.strictEqual(node_buffer[256], 0);
+assert.strictEqual(node_buffer.toString("hex", 0, 3), "000102");
+
+let empty_buffer = addon.test7(0);
+assert.ok(Buffer.isBuffer(empty_buffer));
+assert.strictEqual(empty_buffer.length, 0);
+
+// mutable access to caller provided memory
+let target = Buffer.alloc(4);
+assert.strictEqual(addon.readInto(target, 7), 4);
+assert.deepStrictEqual([...target], [7, 7, 7, 7]);
+
+let array_buffer = new ArrayBuffer(3);
+addon.readInto(array_buffer, 9);
+assert.deepStrictEqual([...new Uint8Array(array_buffer)], [9, 9, 9]);
+
+let window = new Uint8Array(array_buffer, 1, 1);
+addon.readInto(window, 1);
+assert.deepStrictEqual([...new Uint8Array(array_buffer)], [9, 1, 9]);
+
+assert.throws(() => addon.readInto(new Int32Array(2), 1), {
+    message: 'invalid type, expected: Buffer, actual: Int32Array'
+});
+
+let left = Buffer.from("abc");
+let right = Buffer.from("xyz");
+addon.swap(left, right);
+assert.strictEqual(left.toString(), "xyz");
+assert.strictEqual(right.toString(), "abc");
+
+let shared = Buffer.from("abcdef");
+assert.throws(() => addon.swap(shared, shared), {
+    message: 'buffer memory is already borrowed by another argument'
+});
+assert.throws(() => addon.swap(shared.subarray(0, 4), shared.subarray(2, 6)), {
+    message: 'buffer memory is already borrowed by another argument'
+});
+// disjoint views of same memory can be borrowed together
+addon.swap(shared.subarray(0, 3), shared.subarray(3, 6));
+assert.strictEqual(shared.toString(), "defabc");
+
+// shared views can't overlap mutable ones either
+let dest = Buffer.alloc(4);
+assert.strictEqual(addon.copyInto(dest, Buffer.from("wxyz")), 4);
+assert.strictEqual(dest.toString(), "wxyz");
+assert.strictEqual(addon.copyFrom(Buffer.from("ab"), dest), 2);
+assert.strictEqual(dest.toString(), "abyz");
+assert.throws(() => addon.copyInto(dest, dest), {
+    message: 'buffer memory is already borrowed mutably by another argument'
+});
+assert.throws(() => addon.copyInto(dest.subarray(0, 2), new Uint8Array(dest.buffer, dest.byteOffset + 1, 2)), {
+    message: 'buffer memory is already borrowed mutably by another argument'
+});
+assert.throws(() => addon.copyFrom(dest, dest.subarray(3)), {
+    message: 'buffer memory is already borrowed by another argument'
+});
+assert.strictEqual(addon.copyInto(dest.subarray(0, 2), dest.subarray(2)), 2);
+assert.strictEqual(dest.toString(), "yzyz");
+
+let text = Buffer.from("hello");
+assert.strictEqual(addon.upperInPlace(text), text);
+assert.strictEqual(text.toString(), "HELLO");
@@ -67,3 +67,21 @@ impl TestObject {
         0.0
     }
 }
+
+/// different class with same layout, instances must not be accepted by `TestObject`
+struct OtherObject {
+    val: Option<f64>,
+}
+
+#[node_bindgen]
+impl OtherObject {
+    #[node_bindgen(constructor)]
+    fn new(val: f64) -> Self {
+        Self { val: Some(val) }
+    }
+
+    #[node_bindgen(getter)]
+    fn value2(&self) -> f64 {
+        self.val.unwrap_or(0.0)
+    }
+}
@@ -9,6 +9,19 @@ assert.equal(t.value2, 20);
 
 assert.equal(addon.simple(5).value2, 5);
 
+// methods can't be called on instances of other classes
+let other = new addon.OtherObject(7);
+assert.equal(other.value2, 7);
+const value2 = Object.getOwnPropertyDescriptor(addon.TestObject.prototype, 'value2').get;
+assert.throws(() => value2.call(other), {
+    message: 'invalid type, expected: TestObject, actual: object of other class'
+});
+assert.throws(() => value2.call({}), {
+    message: 'invalid type, expected: TestObject, actual: object of other class'
+});
+assert.throws(() => addon.TestObject.prototype.test.call(other), TypeError);
+assert.equal(addon.TestObject.prototype.test.call(t), 0);
+
 addon.create(10).then((test_object) => {
     console.log("test value is %s", test_object.value2);
 });
\ No newline at end of file
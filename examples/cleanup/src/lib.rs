@@ -15,3 +15,61 @@ fn init(env: JsEnv) -> Result<(), NjError> {
 unsafe extern "C" fn my_cleanup(_arg: *mut ::std::os::raw::c_void) {
     println!("I'm called from node to do cleanup");
 }
+
+/// print name when env exits
+#[node_bindgen]
+fn on_exit(name: String, env: JsEnv) -> Result<(), NjError> {
+    env.on_cleanup(move || println!("cleanup {name}"))?;
+    Ok(())
+}
+
+/// add hook and remove it right away
+#[node_bindgen]
+fn on_exit_removed(name: String, env: JsEnv) -> Result<bool, NjError> {
+    let hook = env.on_cleanup(move || println!("cleanup {name}"))?;
+    hook.remove()
+}
+
+/// print name when env exits, exit waits until cleanup is done
+#[node_bindgen]
+fn on_exit_async(name: String, env: JsEnv) -> Result<(), NjError> {
+    env.on_async_cleanup(move |done| {
+        println!("async cleanup {name}");
+        done.done().expect("async cleanup should finish");
+    })?;
+    Ok(())
+}
+
+/// cleanup finishes in other thread after its work is done, exit waits for it
+#[node_bindgen]
+fn on_exit_async_thread(name: String, env: JsEnv) -> Result<(), NjError> {
+    env.on_async_cleanup(move |done| {
+        let done = done
+            .into_thread_safe()
+            .expect("thread safe token should be created");
+        std::thread::spawn(move || {
+            std::thread::sleep(std::time::Duration::from_millis(200));
+            println!("async cleanup {name} finished in thread");
+            done.done().expect("async cleanup should finish");
+        });
+    })?;
+    Ok(())
+}
+
+/// token is dropped without calling `done`, exit doesn't wait for it
+#[node_bindgen]
+fn on_exit_async_dropped(name: String, env: JsEnv) -> Result<(), NjError> {
+    env.on_async_cleanup(move |_done| {
+        println!("async cleanup {name}");
+    })?;
+    Ok(())
+}
+
+#[node_bindgen]
+fn on_exit_async_removed(name: String, env: JsEnv) -> Result<bool, NjError> {
+    let hook = env.on_async_cleanup(move |done| {
+        println!("async cleanup {name}");
+        done.done().expect("async cleanup should finish");
+    })?;
+    hook.remove()
+}
@@ -1,6 +1,41 @@
 const assert = require('assert');
+const { spawnSync } = require('child_process');
+const { Worker, isMainThread } = require('worker_threads');
 
 let addon = require('./dist');
-const { format } = require('path');
 
-addon.init();
\ No newline at end of file
+if (!isMainThread) {
+    addon.onExit("worker");
+} else if (process.argv[2] === "child") {
+    addon.init();
+    addon.onExitAsyncThread("thread");
+    addon.onExit("first");
+    addon.onExit("second");
+    addon.onExitAsync("async");
+    addon.onExitAsyncDropped("async dropped");
+    assert.strictEqual(addon.onExitRemoved("removed"), true);
+    assert.strictEqual(addon.onExitAsyncRemoved("async removed"), true);
+
+    const worker = new Worker(__filename);
+    worker.on('exit', () => console.log("worker exited"));
+} else {
+    const child = spawnSync(process.execPath, [__filename, "child"], { encoding: "utf8", timeout: 10000 });
+    assert.strictEqual(child.status, 0, child.stderr);
+
+    const lines = child.stdout.trim().split("\n");
+    assert.deepStrictEqual(lines, [
+        "init",
+        "cleanup worker",
+        "worker exited",
+        // hooks run in reverse order of registration
+        "async cleanup async dropped",
+        "async cleanup async",
+        "cleanup second",
+        "cleanup first",
+        "I'm called from node to do cleanup",
+        // exit waits until cleanup finished by thread
+        "async cleanup thread finished in thread",
+    ]);
+
+    console.log("cleanup tests succeed");
+}
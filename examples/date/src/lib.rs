@@ -0,0 +1,31 @@
+use std::time::{Duration, SystemTime};
+
+use chrono::{DateTime, Utc};
+
+use node_bindgen::derive::node_bindgen;
+
+/// add seconds to date
+#[node_bindgen]
+fn add_seconds(time: SystemTime, seconds: u32) -> SystemTime {
+    time + Duration::from_secs(seconds as u64)
+}
+
+/// unix epoch as Date
+#[node_bindgen]
+fn epoch() -> SystemTime {
+    SystemTime::UNIX_EPOCH
+}
+
+/// format date as RFC 3339 string
+#[node_bindgen]
+fn to_rfc3339(time: DateTime<Utc>) -> String {
+    time.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// parse RFC 3339 string into Date
+#[node_bindgen]
+fn from_rfc3339(value: String) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(&value)
+        .map(|time| time.with_timezone(&Utc))
+        .map_err(|err| err.to_string())
+}
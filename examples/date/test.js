@@ -0,0 +1,34 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+let now = new Date(2021, 5, 1, 12, 30, 15, 250);
+let later = addon.addSeconds(now, 90);
+assert.ok(later instanceof Date);
+assert.strictEqual(later.getTime(), now.getTime() + 90000);
+
+let before_epoch = new Date(-86400000);
+assert.strictEqual(addon.addSeconds(before_epoch, 1).getTime(), -86399000);
+
+assert.strictEqual(addon.epoch().getTime(), 0);
+
+assert.strictEqual(addon.toRfc3339(new Date(Date.UTC(2020, 0, 2, 3, 4, 5, 6))), "2020-01-02T03:04:05.006Z");
+
+let parsed = addon.fromRfc3339("2020-01-02T03:04:05.006+01:00");
+assert.ok(parsed instanceof Date);
+assert.strictEqual(parsed.getTime(), Date.UTC(2020, 0, 2, 2, 4, 5, 6));
+
+assert.throws(() => addon.addSeconds(new Date("foo"), 1), {
+    message: 'Invalid Date'
+});
+
+assert.throws(() => addon.addSeconds(new Date(8.64e15), 1), {
+    name: 'RangeError',
+    message: 'value 8640000000001000 out of range for Date'
+});
+
+assert.throws(() => addon.toRfc3339(1000), {
+    message: 'invalid type, expected: Date, actual: number'
+});
+
+console.log("date test succeed");
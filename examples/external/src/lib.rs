@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::external::JsExternal;
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+struct Connection {
+    id: u32,
+    host: String,
+}
+
+struct Cursor {
+    position: u32,
+}
+
+/// open connection handle
+#[node_bindgen]
+fn connect(host: String) -> JsExternal<Connection> {
+    JsExternal::new(Connection {
+        id: NEXT_ID.fetch_add(1, Ordering::SeqCst),
+        host,
+    })
+}
+
+#[node_bindgen]
+fn connection_id(connection: JsExternal<Connection>) -> u32 {
+    connection.id
+}
+
+#[node_bindgen]
+fn connection_host(connection: JsExternal<Connection>) -> String {
+    connection.host.clone()
+}
+
+/// create cursor handle
+#[node_bindgen]
+fn cursor(position: u32) -> JsExternal<Cursor> {
+    JsExternal::new(Cursor { position })
+}
+
+#[node_bindgen]
+fn cursor_position(cursor: JsExternal<Cursor>) -> u32 {
+    cursor.position
+}
+
+/// return same handle back to JS
+#[node_bindgen]
+fn same(connection: JsExternal<Connection>) -> JsExternal<Connection> {
+    connection
+}
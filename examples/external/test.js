@@ -0,0 +1,26 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+let first = addon.connect("localhost");
+let second = addon.connect("example.com");
+assert.strictEqual(typeof first, "object");
+assert.strictEqual(Object.keys(first).length, 0);
+
+assert.strictEqual(addon.connectionId(first), 1);
+assert.strictEqual(addon.connectionId(second), 2);
+assert.strictEqual(addon.connectionHost(second), "example.com");
+assert.strictEqual(addon.same(first), first);
+
+let cursor = addon.cursor(10);
+assert.strictEqual(addon.cursorPosition(cursor), 10);
+
+assert.throws(() => addon.cursorPosition(first), {
+    message: /^invalid type, expected: JsExternal<.*Cursor>, actual: external of other type$/
+});
+
+assert.throws(() => addon.connectionId({}), {
+    message: 'invalid type, expected: external, actual: other'
+});
+
+console.log("external test succeed");
@@ -1,5 +1,10 @@
+use std::cell::RefCell;
+
 use node_bindgen::derive::node_bindgen;
 use node_bindgen::core::NjError;
+use node_bindgen::core::coerce::Coerced;
+use node_bindgen::core::function::JsFn;
+use node_bindgen::core::val::JsObject;
 
 #[node_bindgen()]
 fn hello(count: i32) -> String {
@@ -58,3 +63,94 @@ fn give_null(null: bool) -> Option<bool> {
 fn give_str(s: &str) -> String {
     s.to_string()
 }
+
+#[node_bindgen]
+fn add_u8(first: u8, second: u8) -> u8 {
+    first.saturating_add(second)
+}
+
+#[node_bindgen]
+fn negate_i16(value: i16) -> i16 {
+    value.saturating_neg()
+}
+
+#[node_bindgen]
+fn half_f32(value: f32) -> f32 {
+    value / 2.0
+}
+
+#[node_bindgen]
+fn next_char(value: char) -> Option<char> {
+    char::from_u32(value as u32 + 1)
+}
+
+#[node_bindgen]
+fn coerce_string(value: Coerced<String>) -> String {
+    value.into_inner()
+}
+
+#[node_bindgen]
+fn coerce_number(value: Coerced<f64>) -> f64 {
+    *value
+}
+
+#[node_bindgen]
+fn coerce_bool(value: Coerced<bool>) -> bool {
+    *value
+}
+
+#[node_bindgen]
+fn coerce_object(value: Coerced<JsObject>) -> Result<Option<String>, NjError> {
+    match value.get_property("length")? {
+        Some(length) => length
+            .as_value::<Coerced<String>>()
+            .map(|l| Some(l.into_inner())),
+        None => Ok(None),
+    }
+}
+
+/// curried add
+#[node_bindgen]
+fn adder(base: f64) -> JsFn<(f64,), f64> {
+    JsFn::new(move |(value,)| base + value).with_name("add")
+}
+
+thread_local! {
+    static SUBSCRIBERS: RefCell<Vec<String>> = const { RefCell::new(vec![]) };
+}
+
+/// register subscriber, returned function removes it
+#[node_bindgen]
+fn subscribe(name: String) -> JsFn<(), bool> {
+    SUBSCRIBERS.with(|subscribers| subscribers.borrow_mut().push(name.clone()));
+    JsFn::new(move |()| {
+        SUBSCRIBERS.with(|subscribers| {
+            let mut subscribers = subscribers.borrow_mut();
+            match subscribers
+                .iter()
+                .position(|subscriber| *subscriber == name)
+            {
+                Some(index) => {
+                    subscribers.remove(index);
+                    true
+                }
+                None => false,
+            }
+        })
+    })
+}
+
+#[node_bindgen]
+fn subscribers() -> Vec<String> {
+    SUBSCRIBERS.with(|subscribers| subscribers.borrow().clone())
+}
+
+/// returned function fails when dividing by zero
+#[node_bindgen]
+fn divider() -> JsFn<(i32, i32), Result<i32, NjError>> {
+    JsFn::new(|(first, second): (i32, i32)| {
+        first
+            .checked_div(second)
+            .ok_or_else(|| NjError::Other("division by zero".to_owned()))
+    })
+}
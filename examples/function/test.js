@@ -48,6 +48,95 @@ for(const string1 in strings) {
 assert.strictEqual(addon.giveStr(Buffer.from("THEQUICKBROWNFOXJUMPSOVERTHELAZYDOG")), "THEQUICKBROWNFOXJUMPSOVERTHELAZYDOG");
 assert.strictEqual(addon.giveStr(Buffer.from("this is a tést 中 of utf-8")), "this is a tést 中 of utf-8");
 
+// narrow numeric types are range checked
+assert.strictEqual(addon.addU8(200, 100), 255);
+assert.throws(() => addon.addU8(256, 0), {
+    name: 'RangeError',
+    message: 'value 256 out of range for u8'
+});
+assert.throws(() => addon.addU8(-1, 0), {
+    name: 'RangeError',
+    message: 'value -1 out of range for u8'
+});
+assert.throws(() => addon.addU8(1.5, 0), {
+    message: 'invalid type, expected: u8, actual: number 1.5'
+});
+assert.throws(() => addon.addU8(NaN, 0), {
+    message: 'invalid type, expected: u8, actual: number NaN'
+});
+assert.strictEqual(addon.negateI16(-32768), 32767);
+assert.throws(() => addon.negateI16(40000), {
+    name: 'RangeError',
+    message: 'value 40000 out of range for i16'
+});
+assert.throws(() => addon.sum(2 ** 31, 0), {
+    name: 'RangeError',
+    message: 'value 2147483648 out of range for i32'
+});
+assert.throws(() => addon.sum(Infinity, 0), {
+    message: 'invalid type, expected: i32, actual: number inf'
+});
+assert.strictEqual(addon.halfF32(3), 1.5);
+assert.throws(() => addon.halfF32(1e39), {
+    name: 'RangeError',
+    message: /^value 1000000000\d+ out of range for f32$/
+});
+assert.strictEqual(addon.nextChar("a"), "b");
+assert.strictEqual(addon.nextChar("😀"), "😁");
+assert.throws(() => addon.nextChar("ab"), {
+    message: 'invalid type, expected: char, actual: string of length 2'
+});
+assert.throws(() => addon.nextChar(""), {
+    message: 'invalid type, expected: char, actual: string of length 0'
+});
+
+// coerced arguments follow js conversion rules
+assert.strictEqual(addon.coerceString(42), "42");
+assert.strictEqual(addon.coerceString(null), "null");
+assert.strictEqual(addon.coerceString({ toString: () => "custom" }), "custom");
+assert.strictEqual(addon.coerceString([1, 2]), "1,2");
+assert.throws(() => addon.coerceString(Symbol("x")), TypeError);
+assert.strictEqual(addon.coerceNumber("12.5"), 12.5);
+assert.strictEqual(addon.coerceNumber(true), 1);
+assert.ok(Number.isNaN(addon.coerceNumber("abc")));
+assert.throws(() => addon.coerceNumber(5n), {
+    message: 'Cannot convert a BigInt value to a number'
+});
+assert.strictEqual(addon.coerceBool(""), false);
+assert.strictEqual(addon.coerceBool("0"), true);
+assert.strictEqual(addon.coerceBool(0), false);
+assert.strictEqual(addon.coerceObject("hello"), "5");
+assert.strictEqual(addon.coerceObject(5), null);
+assert.throws(() => addon.coerceObject(undefined), TypeError);
+
+// rust closures returned as functions
+const add5 = addon.adder(5);
+assert.strictEqual(typeof add5, "function");
+assert.strictEqual(add5.name, "add");
+assert.strictEqual(add5(2), 7);
+assert.strictEqual(addon.adder(1)(-1.5), -0.5);
+assert.throws(() => add5("x"), {
+    message: 'invalid type, expected: number, actual: string'
+});
+assert.throws(() => add5(), {
+    message: 'trying to get arg at: 0 but only 0 args passed'
+});
+
+const unsubscribeA = addon.subscribe("a");
+const unsubscribeB = addon.subscribe("b");
+assert.deepStrictEqual(addon.subscribers(), ["a", "b"]);
+assert.strictEqual(unsubscribeA(), true);
+assert.strictEqual(unsubscribeA(), false);
+assert.deepStrictEqual(addon.subscribers(), ["b"]);
+assert.strictEqual(unsubscribeB(), true);
+assert.deepStrictEqual(addon.subscribers(), []);
+
+const divide = addon.divider();
+assert.strictEqual(divide(7, 2), 3);
+assert.throws(() => divide(1, 0), {
+    message: 'division by zero'
+});
+
 console.log("function tests succeed");
 
 /*
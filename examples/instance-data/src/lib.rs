@@ -0,0 +1,42 @@
+use std::cell::Cell;
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::NjError;
+use node_bindgen::core::val::JsEnv;
+
+/// state of addon instance, each env has its own copy
+struct Config {
+    prefix: String,
+}
+
+struct Counter(Cell<u32>);
+
+#[node_bindgen]
+fn configure(prefix: String, env: JsEnv) -> Result<(), NjError> {
+    env.set_instance_data(Config { prefix })
+}
+
+#[node_bindgen]
+fn greet(name: String, env: JsEnv) -> Result<String, NjError> {
+    match env.instance_data::<Config>()? {
+        Some(config) => Ok(format!("{} {name}", config.prefix)),
+        None => Err(NjError::Other("not configured".to_owned())),
+    }
+}
+
+/// increasing id, counted separately in each env
+#[node_bindgen]
+fn next_id(env: JsEnv) -> Result<u32, NjError> {
+    let counter = match env.instance_data::<Counter>()? {
+        Some(counter) => counter,
+        None => {
+            env.set_instance_data(Counter(Cell::new(0)))?;
+            env.instance_data::<Counter>()?
+                .ok_or_else(|| NjError::Other("counter is not set".to_owned()))?
+        }
+    };
+
+    let id = counter.0.get() + 1;
+    counter.0.set(id);
+    Ok(id)
+}
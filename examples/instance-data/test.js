@@ -0,0 +1,19 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+assert.throws(() => addon.greet("world"), {
+    message: 'not configured'
+});
+addon.configure("hello");
+assert.strictEqual(addon.greet("world"), "hello world");
+assert.throws(() => addon.configure("hi"), {
+    message: /^instance data of type .*Config is already set$/
+});
+assert.strictEqual(addon.greet("again"), "hello again");
+
+assert.strictEqual(addon.nextId(), 1);
+assert.strictEqual(addon.nextId(), 2);
+assert.strictEqual(addon.nextId(), 3);
+
+console.log("instance data tests succeed");
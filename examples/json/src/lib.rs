@@ -33,6 +33,19 @@ enum ErrorType {
     UnitError,
 }
 
+#[node_bindgen]
+enum Shape {
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+    Empty,
+}
+
+#[node_bindgen]
+struct WithOptional {
+    name: String,
+    label: Option<String>,
+}
+
 #[node_bindgen]
 struct WithSerdeJson {
     val: Value,
@@ -116,3 +129,23 @@ fn with_serde_json() -> WithSerdeJson {
         val: Value::Object(map),
     }
 }
+
+#[node_bindgen]
+fn echo_standard_json(json: StandardJson) -> StandardJson {
+    json
+}
+
+#[node_bindgen]
+fn echo_multilevel_json(json: Outer) -> Outer {
+    json
+}
+
+#[node_bindgen]
+fn echo_shape(shape: Shape) -> Shape {
+    shape
+}
+
+#[node_bindgen]
+fn echo_with_optional(json: WithOptional) -> WithOptional {
+    json
+}
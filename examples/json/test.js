@@ -46,3 +46,34 @@ assert.deepStrictEqual(addon.withSerdeJson(), {
         second: "hello"
     }
 }, "serde_json serialization")
+
+assert.deepStrictEqual(addon.echoStandardJson({
+    someName: "Jane",
+    aNumber: 42
+}), {
+    someName: "Jane",
+    aNumber: 42
+}, "standard json round trip");
+assert.deepStrictEqual(addon.echoMultilevelJson({
+    val: ["nested"]
+}), {
+    val: ["nested"]
+}, "multilevel json round trip");
+assert.throws(() => addon.echoStandardJson({ someName: "Jane" }),
+    /missing property aNumber for StandardJson/,
+    "missing field");
+assert.throws(() => addon.echoMultilevelJson({ val: ["a", "b"] }),
+    /Inner must have exactly length 1/,
+    "tuple struct length");
+
+assert.deepStrictEqual(addon.echoShape({ circle: [1.5] }), { circle: [1.5] }, "unnamed enum variant round trip");
+assert.deepStrictEqual(addon.echoShape({ rect: { width: 2, height: 3 } }),
+    { rect: { width: 2, height: 3 } },
+    "named enum variant round trip");
+assert.strictEqual(addon.echoShape("Empty"), "Empty", "unit enum variant round trip");
+assert.throws(() => addon.echoShape("Triangle"), /unknown variant Triangle for Shape/);
+assert.throws(() => addon.echoShape({ triangle: [] }), /no matching variant found for Shape/);
+
+assert.deepStrictEqual(addon.echoWithOptional({ name: "a" }), { name: "a", label: null }, "missing optional field");
+assert.deepStrictEqual(addon.echoWithOptional({ name: "a", label: undefined }), { name: "a", label: null }, "undefined optional field");
+assert.deepStrictEqual(addon.echoWithOptional({ name: "a", label: "b" }), { name: "a", label: "b" }, "present optional field");
@@ -0,0 +1,109 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use indexmap::{IndexMap, IndexSet};
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::bigint::{BigInt, Sign};
+use node_bindgen::core::collection::{JsMap, JsSet};
+
+/// sum values of each key
+#[node_bindgen]
+fn total(values: HashMap<String, f64>) -> f64 {
+    values.values().fold(0.0, |acc, v| acc + v)
+}
+
+/// count characters of each word
+#[node_bindgen]
+fn word_lengths(words: Vec<String>) -> BTreeMap<String, u32> {
+    words
+        .into_iter()
+        .map(|word| {
+            let len = word.chars().count() as u32;
+            (word, len)
+        })
+        .collect()
+}
+
+/// invert object of strings
+#[node_bindgen]
+fn invert(values: BTreeMap<String, String>) -> HashMap<String, String> {
+    values.into_iter().map(|(k, v)| (v, k)).collect()
+}
+
+/// nested dictionary
+#[node_bindgen]
+fn group_by_len(words: Vec<String>) -> HashMap<String, Vec<String>> {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    for word in words {
+        groups.entry(word.len().to_string()).or_default().push(word);
+    }
+    groups
+}
+
+/// count occurrences of numbers, keeping order of first appearance
+#[node_bindgen]
+fn count_numbers(values: Vec<i32>) -> JsMap<i32, u32> {
+    let mut counts: IndexMap<i32, u32> = IndexMap::new();
+    for value in values {
+        *counts.entry(value).or_default() += 1;
+    }
+    counts.into()
+}
+
+/// sum values of map with BigInt keys, keyed by sign of key
+#[node_bindgen]
+fn sum_by_sign(values: JsMap<BigInt, f64>) -> JsMap<String, f64> {
+    let mut sums: HashMap<String, f64> = HashMap::new();
+    for (key, value) in values {
+        let sign = if key.sign() == Sign::Minus {
+            "negative"
+        } else {
+            "positive"
+        };
+        *sums.entry(sign.to_owned()).or_default() += value;
+    }
+    let sums: BTreeMap<String, f64> = sums.into_iter().collect();
+    sums.into()
+}
+
+/// remove duplicate strings
+#[node_bindgen]
+fn unique(values: Vec<String>) -> JsSet<String> {
+    values.into_iter().collect::<IndexSet<String>>().into()
+}
+
+/// sort values of set
+#[node_bindgen]
+fn sorted(values: JsSet<i32>) -> Vec<i32> {
+    let values: HashSet<i32> = values.into_iter().collect();
+    let mut values: Vec<i32> = values.into_iter().collect();
+    values.sort();
+    values
+}
+
+/// sum values of JS Map or plain object by key
+#[node_bindgen]
+fn sum_by_key(values: HashMap<u32, Vec<f64>>) -> BTreeMap<String, f64> {
+    values
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.iter().sum()))
+        .collect()
+}
+
+/// keys of JS Map or plain object in insertion order
+#[node_bindgen]
+fn ordered_keys(values: IndexMap<String, f64>) -> Vec<String> {
+    values.into_keys().collect()
+}
+
+/// number of distinct values of JS Set
+#[node_bindgen]
+fn distinct(values: HashSet<String>) -> u32 {
+    values.len() as u32
+}
+
+/// JS Set converted back with insertion order kept
+#[node_bindgen]
+fn reversed(values: IndexSet<i32>) -> JsSet<i32> {
+    values.into_iter().rev().collect()
+}
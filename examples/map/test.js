@@ -0,0 +1,95 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+assert.strictEqual(addon.total({ a: 1.5, b: 2, c: 3.5 }), 7);
+assert.strictEqual(addon.total({}), 0);
+
+assert.deepStrictEqual(addon.wordLengths(["apple", "kiwi", "fig"]), { apple: 5, kiwi: 4, fig: 3 });
+assert.deepStrictEqual(Object.keys(addon.wordLengths(["b", "c", "a"])), ["a", "b", "c"]);
+
+assert.deepStrictEqual(addon.invert({ one: "1", two: "2" }), { "1": "one", "2": "two" });
+
+assert.deepStrictEqual(addon.groupByLen(["a", "bb", "cc", "d"]), { "1": ["a", "d"], "2": ["bb", "cc"] });
+
+assert.throws(() => addon.total({ a: "x" }), {
+    message: 'invalid type, expected: number, actual: string'
+});
+
+assert.throws(() => addon.total(5), {
+    message: 'invalid type, expected: object, actual: number'
+});
+
+assert.throws(() => addon.total(null), {
+    message: 'invalid type, expected: object, actual: null'
+});
+
+let counts = addon.countNumbers([3, 1, 3, 2, 1, 3]);
+assert.ok(counts instanceof Map);
+assert.deepStrictEqual([...counts.entries()], [[3, 3], [1, 2], [2, 1]]);
+
+let sums = addon.sumBySign(new Map([[-5n, 1.5], [10n, 2], [-(2n ** 70n), 3], [2n ** 64n, 4]]));
+assert.deepStrictEqual([...sums.entries()], [["negative", 4.5], ["positive", 6]]);
+
+assert.throws(() => addon.sumBySign({ a: 1 }), {
+    message: 'invalid type, expected: Map, actual: other'
+});
+
+assert.throws(() => addon.sumBySign(new Map([[1, 1]])), {
+    message: 'invalid type, expected: big_int, actual: number'
+});
+
+let unique = addon.unique(["b", "a", "b", "c", "a"]);
+assert.ok(unique instanceof Set);
+assert.deepStrictEqual([...unique], ["b", "a", "c"]);
+
+assert.deepStrictEqual(addon.sorted(new Set([5, -1, 3])), [-1, 3, 5]);
+assert.deepStrictEqual(addon.sorted(new Set()), []);
+
+assert.throws(() => addon.sorted([1, 2]), {
+    message: 'invalid type, expected: Set, actual: other'
+});
+
+assert.deepStrictEqual(addon.sumByKey(new Map([[1, [1, 2]], [20, [0.5]]])), { "1": 3, "20": 0.5 });
+// keys of plain objects are strings
+assert.throws(() => addon.sumByKey({ 7: [1, 1] }), {
+    message: 'invalid type, expected: number, actual: string'
+});
+assert.throws(() => addon.sumByKey(new Map([["a", []]])), {
+    message: 'invalid type, expected: number, actual: string'
+});
+assert.strictEqual(addon.total(new Map([["a", 1], ["b", 2]])), 3);
+
+assert.deepStrictEqual(addon.orderedKeys(new Map([["z", 1], ["a", 2], ["m", 3]])), ["z", "a", "m"]);
+assert.deepStrictEqual(addon.orderedKeys({ z: 1, a: 2 }), ["z", "a"]);
+
+assert.strictEqual(addon.distinct(new Set(["a", "b", "a"])), 2);
+assert.throws(() => addon.distinct(["a"]), {
+    message: 'invalid type, expected: Set, actual: other'
+});
+
+assert.deepStrictEqual([...addon.reversed(new Set([1, 2, 3]))], [3, 2, 1]);
+
+// builtins are captured when addon is loaded, so later monkeypatching doesn't change results
+const { set, entries } = Map.prototype;
+const { add, values } = Set.prototype;
+const arrayFrom = Array.from;
+const patchedSet = new Set([2, 1]);
+const patchedMap = new Map([["b", 1]]);
+Map.prototype.set = function () { throw new Error("patched set"); };
+Map.prototype.entries = function () { throw new Error("patched entries"); };
+Set.prototype.add = function () { throw new Error("patched add"); };
+Set.prototype.values = function () { throw new Error("patched values"); };
+Array.from = function () { throw new Error("patched from"); };
+try {
+    assert.deepStrictEqual(addon.sorted(patchedSet), [1, 2]);
+    assert.strictEqual(addon.countNumbers([4, 4]).size, 1);
+    assert.strictEqual(addon.unique(["a", "a"]).size, 1);
+    assert.deepStrictEqual(addon.orderedKeys(patchedMap), ["b"]);
+} finally {
+    Object.assign(Map.prototype, { set, entries });
+    Object.assign(Set.prototype, { add, values });
+    Array.from = arrayFrom;
+}
+
+console.log("map test succeed");
@@ -0,0 +1,89 @@
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::NjError;
+use node_bindgen::core::val::{JsObject, KeyCollectionMode, KeyFilter};
+
+/// own enumerable keys
+#[node_bindgen]
+fn keys(object: JsObject) -> Result<Vec<String>, NjError> {
+    object.keys()
+}
+
+/// number of string and symbol keys, including non enumerable and inherited ones
+#[node_bindgen]
+fn count_all_keys(object: JsObject) -> Result<u32, NjError> {
+    let keys = object.property_keys(
+        KeyCollectionMode::IncludePrototypes,
+        KeyFilter::ALL_PROPERTIES,
+    )?;
+    Ok(keys.len() as u32)
+}
+
+/// own writable keys, symbols are skipped
+#[node_bindgen]
+fn writable_keys(object: JsObject) -> Result<Vec<String>, NjError> {
+    object
+        .property_keys(
+            KeyCollectionMode::OwnOnly,
+            KeyFilter::WRITABLE | KeyFilter::SKIP_SYMBOLS,
+        )?
+        .iter()
+        .map(|key| key.as_value())
+        .collect()
+}
+
+/// sum of numeric values
+#[node_bindgen]
+fn sum_entries(object: JsObject) -> Result<f64, NjError> {
+    Ok(object
+        .entries::<f64>()?
+        .into_iter()
+        .map(|(_, value)| value)
+        .sum())
+}
+
+/// typed property access
+#[node_bindgen]
+fn describe(object: JsObject) -> Result<String, NjError> {
+    let name: String = object
+        .get("name")?
+        .unwrap_or_else(|| "anonymous".to_owned());
+    match object.get::<u32, _>("age")? {
+        Some(age) => Ok(format!("{name} ({age})")),
+        None => Ok(name),
+    }
+}
+
+#[node_bindgen]
+fn has_own(object: JsObject, key: String) -> Result<(bool, bool), NjError> {
+    Ok((object.has_own_property(&key)?, object.has_property(&key)?))
+}
+
+/// delete property, false if property is not configurable
+#[node_bindgen]
+fn remove(mut object: JsObject, key: String) -> Result<bool, NjError> {
+    object.delete_property(&key)
+}
+
+/// length of prototype chain
+#[node_bindgen]
+fn prototype_depth(object: JsObject) -> Result<u32, NjError> {
+    let mut depth = 0;
+    let mut current = object.prototype()?;
+    while let Some(prototype) = current {
+        depth += 1;
+        current = prototype.prototype()?;
+    }
+    Ok(depth)
+}
+
+#[node_bindgen]
+fn freeze(mut object: JsObject) -> Result<JsObject, NjError> {
+    object.freeze()?;
+    Ok(object)
+}
+
+#[node_bindgen]
+fn seal(mut object: JsObject) -> Result<JsObject, NjError> {
+    object.seal()?;
+    Ok(object)
+}
@@ -0,0 +1,58 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+class Base {
+    inherited() {}
+}
+const object = Object.assign(new Base(), { b: 1, a: 2, 3: 4 });
+object[Symbol("hidden")] = 5;
+Object.defineProperty(object, "fixed", { value: 6, enumerable: false, writable: false });
+
+assert.deepStrictEqual(addon.keys(object), ["3", "b", "a"]);
+assert.deepStrictEqual(addon.keys({}), []);
+assert.deepStrictEqual(addon.keys([10, 20]), ["0", "1"]);
+assert.deepStrictEqual(addon.writableKeys(object), ["3", "b", "a"]);
+const prototype = Object.create(null);
+prototype.inherited = 1;
+const child = Object.create(prototype);
+child.own = 2;
+child[Symbol("symbol")] = 3;
+Object.defineProperty(child, "hidden", { value: 4, enumerable: false });
+assert.strictEqual(addon.countAllKeys(child), 4);
+
+assert.strictEqual(addon.sumEntries({ x: 1.5, y: 2 }), 3.5);
+assert.throws(() => addon.sumEntries({ x: "1" }), {
+    message: 'invalid type, expected: number, actual: string'
+});
+
+assert.strictEqual(addon.describe({ name: "alice", age: 30 }), "alice (30)");
+assert.strictEqual(addon.describe({ name: "bob" }), "bob");
+assert.strictEqual(addon.describe({ age: undefined }), "anonymous");
+assert.throws(() => addon.describe({ name: 5 }), {
+    message: 'invalid type, expected: string, actual: number'
+});
+
+assert.deepStrictEqual(addon.hasOwn(object, "a"), [true, true]);
+assert.deepStrictEqual(addon.hasOwn(object, "inherited"), [false, true]);
+assert.deepStrictEqual(addon.hasOwn(object, "missing"), [false, false]);
+
+const removable = { a: 1, b: 2 };
+assert.strictEqual(addon.remove(removable, "a"), true);
+assert.deepStrictEqual(removable, { b: 2 });
+assert.strictEqual(addon.remove(object, "fixed"), false);
+assert.strictEqual(object.fixed, 6);
+
+assert.strictEqual(addon.prototypeDepth(object), 2);
+assert.strictEqual(addon.prototypeDepth({}), 1);
+assert.strictEqual(addon.prototypeDepth(Object.create(null)), 0);
+
+const frozen = addon.freeze({ a: 1 });
+assert.ok(Object.isFrozen(frozen));
+const sealed = addon.seal({ a: 1 });
+assert.ok(Object.isSealed(sealed));
+assert.ok(!Object.isFrozen(sealed));
+sealed.a = 2;
+assert.strictEqual(sealed.a, 2);
+
+console.log("object tests succeed");
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::NjError;
+use node_bindgen::core::val::{JsEnv, JsObject, JsCallbackFunction};
+use node_bindgen::core::reference::{JsRef, JsWeak};
+
+/// keeps JS values alive between calls
+struct Holder {
+    value: Option<JsRef<JsObject>>,
+    listener: Option<JsRef<JsCallbackFunction>>,
+    watched: Option<JsWeak<JsObject>>,
+}
+
+#[node_bindgen]
+impl Holder {
+    #[node_bindgen(constructor)]
+    fn new() -> Self {
+        Self {
+            value: None,
+            listener: None,
+            watched: None,
+        }
+    }
+
+    #[node_bindgen]
+    fn hold(&mut self, value: JsRef<JsObject>) {
+        self.value = Some(value);
+    }
+
+    #[node_bindgen]
+    fn release(&mut self) {
+        self.value = None;
+    }
+
+    #[node_bindgen]
+    fn value(&self, env: JsEnv) -> Result<Option<JsObject>, NjError> {
+        self.value.as_ref().map(|value| value.get(&env)).transpose()
+    }
+
+    /// decrease reference count, value can be collected when count reaches 0
+    #[node_bindgen]
+    fn weaken(&self) -> Result<u32, NjError> {
+        self.held()?.dec_ref()
+    }
+
+    #[node_bindgen]
+    fn strengthen(&self) -> Result<u32, NjError> {
+        self.held()?.inc_ref()
+    }
+
+    /// keep weak reference to held value, it can be collected once holder releases it
+    #[node_bindgen]
+    fn watch(&mut self, env: JsEnv) -> Result<(), NjError> {
+        self.watched = Some(self.held()?.downgrade(&env)?);
+        Ok(())
+    }
+
+    #[node_bindgen]
+    fn watched(&self, env: JsEnv) -> Result<Option<JsObject>, NjError> {
+        match &self.watched {
+            Some(watched) => watched.upgrade(&env),
+            None => Ok(None),
+        }
+    }
+
+    #[node_bindgen]
+    fn listen(&mut self, listener: JsRef<JsCallbackFunction>) {
+        self.listener = Some(listener);
+    }
+
+    /// call listener registered in earlier call
+    #[node_bindgen]
+    fn emit(&self, message: String, env: JsEnv) -> Result<(), NjError> {
+        if let Some(listener) = &self.listener {
+            listener.get(&env)?.call(vec![message])?;
+        }
+        Ok(())
+    }
+}
+
+/// maps ids to JS wrapper objects without keeping them alive
+struct Cache {
+    wrappers: HashMap<u32, JsWeak<JsObject>>,
+}
+
+#[node_bindgen]
+impl Cache {
+    #[node_bindgen(constructor)]
+    fn new() -> Self {
+        Self {
+            wrappers: HashMap::new(),
+        }
+    }
+
+    #[node_bindgen]
+    fn insert(&mut self, id: u32, wrapper: JsWeak<JsObject>) {
+        self.wrappers.insert(id, wrapper);
+    }
+
+    #[node_bindgen]
+    fn lookup(&self, id: u32, env: JsEnv) -> Result<Option<JsObject>, NjError> {
+        match self.wrappers.get(&id) {
+            Some(wrapper) => wrapper.upgrade(&env),
+            None => Ok(None),
+        }
+    }
+
+    /// remove entries which wrappers were collected, return number of remaining entries
+    #[node_bindgen]
+    fn prune(&mut self, env: JsEnv) -> Result<u32, NjError> {
+        let mut collected = vec![];
+        for (id, wrapper) in &self.wrappers {
+            if wrapper.is_collected(&env)? {
+                collected.push(*id);
+            }
+        }
+        for id in collected {
+            self.wrappers.remove(&id);
+        }
+        Ok(self.wrappers.len() as u32)
+    }
+}
+
+impl Holder {
+    fn held(&self) -> Result<&JsRef<JsObject>, NjError> {
+        self.value
+            .as_ref()
+            .ok_or_else(|| NjError::Other("nothing is held".to_owned()))
+    }
+}
@@ -0,0 +1,90 @@
+const assert = require('assert');
+const v8 = require('v8');
+const vm = require('vm');
+
+let addon = require('./dist');
+
+v8.setFlagsFromString('--expose-gc');
+const gc = vm.runInNewContext('gc');
+
+async function collect() {
+    for (let i = 0; i < 5; i++) {
+        gc();
+        await new Promise((resolve) => setImmediate(resolve));
+    }
+}
+
+async function main() {
+    const holder = new addon.Holder();
+    assert.strictEqual(holder.value(), null);
+    assert.throws(() => holder.weaken(), {
+        message: 'nothing is held'
+    });
+    assert.throws(() => holder.hold(5), {
+        message: 'invalid type, expected: other, actual: number'
+    });
+
+    holder.hold({ name: "kept" });
+    await collect();
+    assert.deepStrictEqual(holder.value(), { name: "kept" });
+
+    const same = {};
+    holder.hold(same);
+    assert.strictEqual(holder.value(), same);
+
+    // weak reference still returns value while it is reachable from JS
+    assert.strictEqual(holder.strengthen(), 2);
+    assert.strictEqual(holder.weaken(), 1);
+    assert.strictEqual(holder.weaken(), 0);
+    await collect();
+    assert.strictEqual(holder.value(), same);
+    assert.strictEqual(holder.strengthen(), 1);
+
+    // value is collected once it is only weakly referenced
+    holder.hold({ name: "temporary" });
+    assert.strictEqual(holder.weaken(), 0);
+    await collect();
+    assert.throws(() => holder.value(), {
+        message: 'referenced value was garbage collected'
+    });
+
+    holder.release();
+    assert.strictEqual(holder.value(), null);
+
+    // weak reference from strong one
+    holder.hold({ name: "watched" });
+    holder.watch();
+    await collect();
+    assert.deepStrictEqual(holder.watched(), { name: "watched" });
+    holder.release();
+    await collect();
+    assert.strictEqual(holder.watched(), null);
+
+    // cache doesn't keep wrappers alive
+    const cache = new addon.Cache();
+    let first = { id: 1 };
+    const second = { id: 2 };
+    cache.insert(1, first);
+    cache.insert(2, second);
+    assert.throws(() => cache.insert(3, "wrapper"), {
+        message: 'invalid type, expected: other, actual: string'
+    });
+    assert.strictEqual(cache.lookup(1), first);
+    assert.strictEqual(cache.lookup(3), null);
+    first = null;
+    await collect();
+    assert.strictEqual(cache.lookup(1), null);
+    assert.strictEqual(cache.lookup(2), second);
+    assert.strictEqual(cache.prune(), 1);
+
+    const messages = [];
+    holder.listen((message) => messages.push(message));
+    await collect();
+    holder.emit("first");
+    holder.emit("second");
+    assert.deepStrictEqual(messages, ["first", "second"]);
+
+    console.log("reference tests succeed");
+}
+
+main();
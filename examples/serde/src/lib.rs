@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Deserialize};
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::sys::napi_value;
+use node_bindgen::core::NjError;
+use node_bindgen::core::val::{JsEnv, JsObject};
+use node_bindgen::core::serde::{to_js, from_js};
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Config {
+    name: String,
+    max_retries: Option<u32>,
+    servers: Vec<Server>,
+    labels: BTreeMap<String, String>,
+    mode: Mode,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+enum Mode {
+    Simple,
+    Weighted(f64),
+    Custom { level: u8 },
+}
+
+/// parse config from js and send it back
+#[node_bindgen]
+fn echo_config(config: JsObject) -> Result<napi_value, NjError> {
+    let env = config.env();
+    let config: Config = from_js(env, config.napi_value())?;
+    to_js(env, &config)
+}
+
+/// create config in rust
+#[node_bindgen]
+fn default_config(env: JsEnv) -> Result<napi_value, NjError> {
+    let config = Config {
+        name: "default".to_owned(),
+        max_retries: None,
+        servers: vec![Server {
+            host: "localhost".to_owned(),
+            port: 9003,
+        }],
+        labels: BTreeMap::from([("env".to_owned(), "dev".to_owned())]),
+        mode: Mode::Simple,
+    };
+
+    to_js(&env, &config)
+}
+
+/// read map with numeric keys and send it back
+#[node_bindgen]
+fn echo_ports(ports: JsObject) -> Result<napi_value, NjError> {
+    let env = ports.env();
+    let ports: BTreeMap<u16, String> = from_js(env, ports.napi_value())?;
+    to_js(env, &ports)
+}
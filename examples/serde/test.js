@@ -0,0 +1,33 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+assert.deepStrictEqual(addon.defaultConfig(), {
+    name: "default",
+    maxRetries: null,
+    servers: [{ host: "localhost", port: 9003 }],
+    labels: { env: "dev" },
+    mode: "Simple"
+}, "serialize config");
+
+const config = {
+    name: "custom",
+    maxRetries: 3,
+    servers: [{ host: "a", port: 1 }, { host: "b", port: 2 }],
+    labels: {},
+    mode: { Custom: { level: 5 } }
+};
+assert.deepStrictEqual(addon.echoConfig(config), config, "round trip config");
+
+assert.deepStrictEqual(addon.echoConfig({ ...config, mode: { Weighted: 0.5 } }).mode,
+    { Weighted: 0.5 }, "newtype variant");
+
+assert.throws(() => addon.echoConfig({ ...config, servers: [{ host: "a", port: 70000 }] }),
+    /invalid value/, "out of range port");
+assert.throws(() => addon.echoConfig({ name: "missing" }),
+    /missing field/, "missing field");
+
+const ports = { 80: "http", 443: "https" };
+assert.deepStrictEqual(addon.echoPorts(ports), ports, "round trip numeric keys");
+assert.throws(() => addon.echoPorts({ http: "http" }), /numeric key/, "non numeric key");
+assert.throws(() => addon.echoPorts({ 70000: "out" }), /numeric key/, "key out of range");
@@ -0,0 +1,45 @@
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::NjError;
+use node_bindgen::core::string::{Latin1String, Utf16String};
+
+/// reverse UTF-16 code units without decoding
+#[node_bindgen]
+fn reverse_units(value: Utf16String) -> Utf16String {
+    value.iter().rev().copied().collect()
+}
+
+/// number of UTF-16 code units, same as js `length`
+#[node_bindgen]
+fn unit_count(value: Utf16String) -> u32 {
+    value.len() as u32
+}
+
+/// join windows path segments
+#[node_bindgen]
+fn join_path(segments: Vec<String>) -> Utf16String {
+    segments.join("\\").as_str().into()
+}
+
+/// decode UTF-16 string, failing on unpaired surrogates
+#[node_bindgen]
+fn strict_decode(value: Utf16String) -> Result<String, NjError> {
+    String::try_from(value).map_err(|err| NjError::Other(err.to_string()))
+}
+
+/// bytes of Latin-1 string
+#[node_bindgen]
+fn latin1_bytes(value: Latin1String) -> Vec<u32> {
+    value.iter().map(|byte| *byte as u32).collect()
+}
+
+/// upper case ASCII letters of Latin-1 string
+#[node_bindgen]
+fn latin1_upper(value: Latin1String) -> Latin1String {
+    value.to_ascii_uppercase().into()
+}
+
+/// encode string as Latin-1
+#[node_bindgen]
+fn to_latin1(value: String) -> Result<Latin1String, NjError> {
+    Latin1String::try_from(value.as_str())
+}
@@ -0,0 +1,41 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+assert.strictEqual(addon.reverseUnits("abc"), "cba");
+assert.strictEqual(addon.reverseUnits(""), "");
+
+// reversing surrogate pair produces unpaired surrogates, which must survive round trip
+let reversed = addon.reverseUnits("a😀");
+assert.strictEqual(reversed.length, 3);
+assert.strictEqual(reversed.charCodeAt(0), 0xDE00);
+assert.strictEqual(addon.reverseUnits(reversed), "a😀");
+
+assert.strictEqual(addon.unitCount("😀é"), 3);
+
+assert.strictEqual(addon.joinPath(["C:", "Users", "Jürgen"]), "C:\\Users\\Jürgen");
+
+assert.strictEqual(addon.strictDecode("héllo 😀"), "héllo 😀");
+assert.throws(() => addon.strictDecode("\uD800"), {
+    message: 'invalid utf-16: lone surrogate found'
+});
+
+assert.deepStrictEqual(addon.latin1Bytes("aé\xff"), [0x61, 0xE9, 0xFF]);
+assert.strictEqual(addon.latin1Upper("café"), "CAFé");
+assert.throws(() => addon.latin1Bytes("a€"), {
+    message: "character '€' can't be encoded as Latin-1"
+});
+assert.throws(() => addon.latin1Bytes("\ud83d"), {
+    message: "character U+D83D can't be encoded as Latin-1"
+});
+
+assert.strictEqual(addon.toLatin1("Grüße"), "Grüße");
+assert.throws(() => addon.toLatin1("€"), {
+    message: "character '€' can't be encoded as Latin-1"
+});
+
+assert.throws(() => addon.unitCount(5), {
+    message: 'invalid type, expected: string, actual: number'
+});
+
+console.log("string test succeed");
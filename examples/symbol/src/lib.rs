@@ -0,0 +1,69 @@
+use std::cell::OnceCell;
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::NjError;
+use node_bindgen::sys::napi_value;
+use node_bindgen::core::val::{JsEnv, JsObject};
+use node_bindgen::core::symbol::JsSymbol;
+
+thread_local! {
+    static PRIVATE_KEY: OnceCell<JsSymbol> = const { OnceCell::new() };
+}
+
+/// create new symbol
+#[node_bindgen]
+fn create_symbol(description: String, env: JsEnv) -> Result<JsSymbol, NjError> {
+    JsSymbol::new(&env, Some(&description))
+}
+
+/// get description of symbol
+#[node_bindgen]
+fn describe(symbol: JsSymbol) -> Result<Option<String>, NjError> {
+    symbol.description()
+}
+
+/// set property using symbol as key
+#[node_bindgen]
+fn tag(mut object: JsObject, symbol: JsSymbol, value: String) -> Result<JsObject, NjError> {
+    let js_value = object.env().create_string_utf8(&value)?;
+    object.set_property(&symbol, js_value)?;
+    Ok(object)
+}
+
+/// get property using symbol as key
+#[node_bindgen]
+fn read_tag(object: JsObject, symbol: JsSymbol) -> Result<Option<String>, NjError> {
+    match object.get_property(&symbol)? {
+        Some(value) => value.as_value().map(Some),
+        None => Ok(None),
+    }
+}
+
+/// check if object implements iterator protocol
+#[node_bindgen]
+fn is_iterable(object: JsObject) -> Result<bool, NjError> {
+    object.has_property(&JsSymbol::iterator(object.env())?)
+}
+
+/// check if object implements async iterator protocol
+#[node_bindgen]
+fn is_async_iterable(object: JsObject) -> Result<bool, NjError> {
+    object.has_property(&JsSymbol::async_iterator(object.env())?)
+}
+
+/// get `Symbol.dispose`
+#[node_bindgen]
+fn dispose_symbol(env: JsEnv) -> Result<JsSymbol, NjError> {
+    JsSymbol::dispose(&env)
+}
+
+/// same symbol is returned on every call
+#[node_bindgen]
+fn private_key(env: JsEnv) -> Result<napi_value, NjError> {
+    PRIVATE_KEY.with(|key| {
+        if key.get().is_none() {
+            let _ = key.set(JsSymbol::new(&env, Some("private"))?);
+        }
+        key.get().expect("key is set").napi_value()
+    })
+}
@@ -0,0 +1,36 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+let symbol = addon.createSymbol("my tag");
+assert.strictEqual(typeof symbol, "symbol");
+assert.strictEqual(symbol.description, "my tag");
+assert.notStrictEqual(symbol, addon.createSymbol("my tag"));
+
+assert.strictEqual(addon.describe(Symbol("hello")), "hello");
+assert.strictEqual(addon.describe(Symbol()), null);
+
+let object = addon.tag({ name: "test" }, symbol, "tagged");
+assert.strictEqual(object[symbol], "tagged");
+assert.deepStrictEqual(Object.keys(object), ["name"]);
+assert.strictEqual(addon.readTag(object, symbol), "tagged");
+assert.strictEqual(addon.readTag(object, Symbol("my tag")), null);
+
+assert.throws(() => addon.readTag(object, "name"), {
+    message: 'invalid type, expected: symbol, actual: string'
+});
+
+assert.strictEqual(addon.isIterable([]), true);
+assert.strictEqual(addon.isIterable({}), false);
+assert.strictEqual(addon.isAsyncIterable({ [Symbol.asyncIterator]: () => null }), true);
+assert.strictEqual(addon.isAsyncIterable(new Map()), false);
+
+if (Symbol.dispose) {
+    assert.strictEqual(addon.disposeSymbol(), Symbol.dispose);
+}
+
+let key = addon.privateKey();
+assert.strictEqual(typeof key, "symbol");
+assert.strictEqual(key, addon.privateKey());
+
+console.log("symbol test succeed");
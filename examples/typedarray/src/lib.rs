@@ -0,0 +1,34 @@
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::typedarray::TypedArray;
+
+/// sum Float64Array
+#[node_bindgen]
+fn sum_f64(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |acc, v| acc + v)
+}
+
+/// sum Int32Array
+#[node_bindgen]
+fn sum_i32(values: &[i32]) -> i32 {
+    values.iter().sum()
+}
+
+/// count bytes of Buffer or Uint8Array
+#[node_bindgen]
+fn byte_len(values: &[u8]) -> u32 {
+    values.len() as u32
+}
+
+/// create Float32Array from rust vector
+#[node_bindgen]
+fn make_f32(count: i32) -> TypedArray<f32> {
+    TypedArray::new((0..count).map(|i| i as f32 * 0.5).collect())
+}
+
+/// copy Uint16Array, reverse and send back
+#[node_bindgen]
+fn reverse_u16(values: TypedArray<u16>) -> TypedArray<u16> {
+    let mut values = values.into_inner();
+    values.reverse();
+    TypedArray::new(values)
+}
@@ -0,0 +1,28 @@
+const assert = require('assert');
+
+let addon = require('./dist');
+
+assert.strictEqual(addon.sumF64(new Float64Array([1.5, 2.5, 3])), 7);
+assert.strictEqual(addon.sumF64(new Float64Array([])), 0);
+assert.strictEqual(addon.sumI32(new Int32Array([1, 2, 3])), 6);
+
+// view with offset into larger buffer
+const buffer = new ArrayBuffer(16);
+new Int32Array(buffer).set([10, 20, 30, 40]);
+assert.strictEqual(addon.sumI32(new Int32Array(buffer, 8, 2)), 70);
+
+assert.strictEqual(addon.byteLen(Buffer.from("hello")), 5);
+assert.strictEqual(addon.byteLen(new Uint8Array(3)), 3);
+
+assert.deepStrictEqual(addon.makeF32(3), new Float32Array([0, 0.5, 1]));
+assert.deepStrictEqual(addon.reverseU16(new Uint16Array([1, 2, 3])), new Uint16Array([3, 2, 1]));
+
+assert.throws(() => addon.sumF64(new Float32Array([1])), {
+    message: 'invalid type, expected: Float64Array, actual: Float32Array'
+});
+assert.throws(() => addon.sumI32([1, 2]), {
+    message: 'invalid type, expected: Int32Array, actual: other'
+});
+assert.throws(() => addon.byteLen("hello"), {
+    message: 'invalid type, expected: Buffer, actual: string'
+});
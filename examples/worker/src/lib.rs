@@ -0,0 +1,60 @@
+use std::cell::Cell;
+
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::{JSClass, NjError, TryIntoJs};
+use node_bindgen::core::val::JsEnv;
+use node_bindgen::sys::napi_value;
+
+struct Visits(Cell<u32>);
+
+/// count calls made from current env
+#[node_bindgen]
+fn visit(env: JsEnv) -> Result<u32, NjError> {
+    if env.instance_data::<Visits>()?.is_none() {
+        env.set_instance_data(Visits(Cell::new(0)))?;
+    }
+
+    let visits = env
+        .instance_data::<Visits>()?
+        .ok_or_else(|| NjError::Other("visits are not set".to_owned()))?;
+    visits.0.set(visits.0.get() + 1);
+    Ok(visits.0.get())
+}
+
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[node_bindgen]
+impl Point {
+    #[node_bindgen(constructor)]
+    fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    #[node_bindgen(getter)]
+    fn x(&self) -> f64 {
+        self.x
+    }
+
+    #[node_bindgen(getter)]
+    fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+/// create instance with constructor of current env
+#[node_bindgen]
+fn origin() -> Origin {
+    Origin
+}
+
+struct Origin;
+
+impl TryIntoJs for Origin {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let zero = js_env.create_double(0.0)?;
+        Point::new_instance(js_env, vec![zero, zero])
+    }
+}
@@ -0,0 +1,52 @@
+const assert = require('assert');
+const { Worker, isMainThread, parentPort, workerData } = require('worker_threads');
+
+function exercise(addon, visits) {
+    for (let i = 1; i <= visits; i++) {
+        assert.strictEqual(addon.visit(), i);
+    }
+
+    const point = new addon.Point(1, 2);
+    assert.strictEqual(point.x, 1);
+    assert.strictEqual(point.y, 2);
+
+    const origin = addon.origin();
+    assert.ok(origin instanceof addon.Point);
+    assert.strictEqual(origin.x, 0);
+}
+
+if (isMainThread) {
+    const addon = require('./dist');
+    exercise(addon, 3);
+
+    const runWorker = (visits) => new Promise((resolve, reject) => {
+        const worker = new Worker(__filename, { workerData: visits });
+        worker.on('message', resolve);
+        worker.on('error', reject);
+        worker.on('exit', (code) => {
+            if (code !== 0) {
+                reject(new Error(`worker exited with code ${code}`));
+            }
+        });
+    });
+
+    Promise.all([runWorker(5), runWorker(2)])
+        .then((results) => {
+            assert.deepStrictEqual(results, [5, 2]);
+            // main env state is not changed by workers
+            assert.strictEqual(addon.visit(), 4);
+            return runWorker(1);
+        })
+        .then((result) => {
+            assert.strictEqual(result, 1);
+            console.log("worker tests succeed");
+        })
+        .catch((err) => {
+            console.error(err);
+            process.exit(1);
+        });
+} else {
+    const addon = require('./dist');
+    exercise(addon, workerData);
+    parentPort.postMessage(workerData);
+}
@@ -1,6 +1,10 @@
 use std::ptr;
 use std::ffi::CString;
+use std::cell::RefCell;
 use std::collections::VecDeque;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::any::{Any, TypeId};
 
 use tracing::instrument;
 use tracing::{error, debug, trace};
@@ -11,6 +15,13 @@ use crate::sys::napi_callback_info;
 use crate::sys::napi_callback_raw;
 use crate::sys::napi_finalize_raw;
 use crate::sys::napi_valuetype;
+use crate::sys::napi_typedarray_type;
+use crate::sys::napi_type_tag;
+use crate::sys::napi_handle_scope;
+use crate::sys::napi_escapable_handle_scope;
+use crate::sys::napi_key_collection_mode;
+use crate::sys::napi_key_filter;
+use crate::sys::napi_key_conversion;
 use crate::sys::napi_get_property;
 use crate::sys::napi_has_property;
 use crate::sys::napi_ref;
@@ -22,8 +33,55 @@ use crate::napi_call_assert;
 use crate::PropertiesBuilder;
 use crate::NjError;
 use crate::JSObjectWrapper;
+use crate::JSClass;
 use crate::JSValue;
 use crate::TryIntoJs;
+use crate::cleanup::{CleanupHook, AsyncCleanupHook, AsyncCleanupDone};
+
+/// type tag unique to rust type, used to check type of wrapped objects and externals.
+/// lifetimes are ignored, so `T<'a>` has same tag as `T<'static>`
+pub fn type_tag_of<T: ?Sized>() -> napi_type_tag {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let type_id = type_id_of::<T>();
+    let hash = |seed: u64| {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        type_id.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    napi_type_tag {
+        lower: hash(0),
+        upper: hash(1),
+    }
+}
+
+/// `TypeId` of type which may borrow data, such as class with lifetime
+pub(crate) fn type_id_of<T: ?Sized>() -> TypeId {
+    trait NonStaticAny {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static;
+    }
+
+    impl<T: ?Sized> NonStaticAny for std::marker::PhantomData<T> {
+        fn type_id(&self) -> TypeId
+        where
+            Self: 'static,
+        {
+            TypeId::of::<T>()
+        }
+    }
+
+    let phantom = std::marker::PhantomData::<T>;
+    // SAFETY: lifetimes are erased before type ids are computed, only lifetime of
+    // trait object is extended and no value of `T` is accessed
+    let phantom: &(dyn NonStaticAny + 'static) = unsafe {
+        std::mem::transmute::<&dyn NonStaticAny, &(dyn NonStaticAny + 'static)>(&phantom)
+    };
+    NonStaticAny::type_id(phantom)
+}
 
 fn napi_value_type_to_string(js_type: napi_valuetype) -> &'static str {
     match js_type {
@@ -88,6 +146,75 @@ impl JsEnv {
         Ok(js_value)
     }
 
+    /// create js string from UTF-16 code units
+    pub fn create_string_utf16(&self, r_string: &[u16]) -> Result<napi_value, NjError> {
+        let mut js_value = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_string_utf16(
+            self.0,
+            r_string.as_ptr(),
+            r_string.len(),
+            &mut js_value
+        ))?;
+        Ok(js_value)
+    }
+
+    /// create js string from ISO-8859-1 (Latin-1) bytes
+    pub fn create_string_latin1(&self, r_string: &[u8]) -> Result<napi_value, NjError> {
+        let mut js_value = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_string_latin1(
+            self.0,
+            r_string.as_ptr() as *const ::std::os::raw::c_char,
+            r_string.len(),
+            &mut js_value
+        ))?;
+        Ok(js_value)
+    }
+
+    /// copy UTF-16 code units of js string
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_value_string_utf16(&self, js_value: napi_value) -> Result<Vec<u16>, NjError> {
+        self.assert_type(js_value, crate::sys::napi_valuetype_napi_string)?;
+
+        let mut len: usize = 0;
+        napi_call_result!(crate::sys::napi_get_value_string_utf16(
+            self.0,
+            js_value,
+            ptr::null_mut(),
+            0,
+            &mut len
+        ))?;
+
+        // reserve space for null terminator
+        let mut chars: Vec<u16> = vec![0; len + 1];
+        let mut read_len: usize = 0;
+        napi_call_result!(crate::sys::napi_get_value_string_utf16(
+            self.0,
+            js_value,
+            chars.as_mut_ptr(),
+            chars.len(),
+            &mut read_len
+        ))?;
+
+        chars.truncate(read_len);
+        Ok(chars)
+    }
+
+    /// copy js string as ISO-8859-1 (Latin-1) bytes.
+    /// string is read as UTF-16 first, so characters outside of Latin-1 are rejected instead of truncated
+    pub fn get_value_string_latin1(&self, js_value: napi_value) -> Result<Vec<u8>, NjError> {
+        self.get_value_string_utf16(js_value)?
+            .into_iter()
+            .map(|unit| {
+                u8::try_from(unit).map_err(|_| {
+                    let character = char::from_u32(unit as u32)
+                        .map(|c| format!("{c:?}"))
+                        .unwrap_or_else(|| format!("U+{unit:04X}"));
+                    NjError::Other(format!("character {character} can't be encoded as Latin-1"))
+                })
+            })
+            .collect()
+    }
+
     pub fn create_double(&self, value: f64) -> Result<napi_value, NjError> {
         let mut result = ptr::null_mut();
         napi_call_result!(crate::sys::napi_create_double(self.0, value, &mut result))?;
@@ -122,6 +249,108 @@ impl JsEnv {
         Ok(result)
     }
 
+    pub fn create_bigint_int64(&self, value: i64) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_bigint_int64(
+            self.0,
+            value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// create BigInt from sign and little endian 64 bit words of magnitude
+    pub fn create_bigint_words(
+        &self,
+        negative: bool,
+        words: &[u64],
+    ) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_bigint_words(
+            self.0,
+            negative as i32,
+            words.len(),
+            words.as_ptr(),
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// get BigInt as i64, also returns whether value was converted without truncation
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_value_bigint_int64(&self, js_value: napi_value) -> Result<(i64, bool), NjError> {
+        let mut value: i64 = 0;
+        let mut lossless = false;
+        napi_call_result!(crate::sys::napi_get_value_bigint_int64(
+            self.0,
+            js_value,
+            &mut value,
+            &mut lossless
+        ))?;
+        Ok((value, lossless))
+    }
+
+    /// get BigInt as u64, also returns whether value was converted without truncation
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_value_bigint_uint64(&self, js_value: napi_value) -> Result<(u64, bool), NjError> {
+        let mut value: u64 = 0;
+        let mut lossless = false;
+        napi_call_result!(crate::sys::napi_get_value_bigint_uint64(
+            self.0,
+            js_value,
+            &mut value,
+            &mut lossless
+        ))?;
+        Ok((value, lossless))
+    }
+
+    /// create node Buffer with copy of data
+    pub fn create_buffer_copy(&self, data: &[u8]) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_buffer_copy(
+            self.0,
+            data.len(),
+            data.as_ptr() as *const ::std::os::raw::c_void,
+            ptr::null_mut(),
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// create new ArrayBuffer with copy of data
+    pub fn create_arraybuffer_copy(&self, data: &[u8]) -> Result<napi_value, NjError> {
+        let mut buffer = ptr::null_mut();
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_arraybuffer(
+            self.0,
+            data.len(),
+            &mut buffer,
+            &mut result
+        ))?;
+        if !data.is_empty() {
+            unsafe {
+                ptr::copy_nonoverlapping(data.as_ptr(), buffer as *mut u8, data.len());
+            }
+        }
+        Ok(result)
+    }
+
+    /// create new unique symbol with optional description
+    pub fn create_symbol(&self, description: Option<&str>) -> Result<napi_value, NjError> {
+        let description = match description {
+            Some(description) => self.create_string_utf8(description)?,
+            None => ptr::null_mut(),
+        };
+
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_symbol(
+            self.0,
+            description,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
     pub fn create_object(&self) -> Result<napi_value, NjError> {
         let mut result = ptr::null_mut();
 
@@ -173,6 +402,156 @@ impl JsEnv {
         Ok(element)
     }
 
+    /// check if array or object has element at index
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn has_element(&self, array: napi_value, index: u32) -> Result<bool, NjError> {
+        let mut result: bool = false;
+        napi_call_result!(crate::sys::napi_has_element(
+            self.0,
+            array,
+            index,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// delete element at index leaving hole, return false if element can't be deleted
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn delete_element(&self, array: napi_value, index: u32) -> Result<bool, NjError> {
+        let mut result: bool = false;
+        napi_call_result!(crate::sys::napi_delete_element(
+            self.0,
+            array,
+            index,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// get length of js array
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_array_length(&self, array: napi_value) -> Result<u32, NjError> {
+        let mut length: u32 = 0;
+
+        napi_call_result!(crate::sys::napi_get_array_length(
+            self.0,
+            array,
+            &mut length
+        ))?;
+        Ok(length)
+    }
+
+    /// set property of object using js value as key
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn set_property(
+        &self,
+        object: napi_value,
+        key: napi_value,
+        value: napi_value,
+    ) -> Result<(), NjError> {
+        napi_call_result!(crate::sys::napi_set_property(self.0, object, key, value))
+    }
+
+    /// get property of object using js value as key
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_property(&self, object: napi_value, key: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_property(
+            self.0,
+            object,
+            key,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// check if object or its prototype chain has property
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn has_property(&self, object: napi_value, key: napi_value) -> Result<bool, NjError> {
+        let mut result: bool = false;
+        napi_call_result!(napi_has_property(self.0, object, key, &mut result))?;
+        Ok(result)
+    }
+
+    /// get array of enumerable string keys of object, including inherited ones
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_property_names(&self, object: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_property_names(
+            self.0,
+            object,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// get array of property keys of object selected by collection mode and filter
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_all_property_names(
+        &self,
+        object: napi_value,
+        key_mode: napi_key_collection_mode,
+        key_filter: napi_key_filter,
+        key_conversion: napi_key_conversion,
+    ) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_all_property_names(
+            self.0,
+            object,
+            key_mode,
+            key_filter,
+            key_conversion,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// check if object has own property, prototype chain is not checked
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn has_own_property(&self, object: napi_value, key: napi_value) -> Result<bool, NjError> {
+        let mut result: bool = false;
+        napi_call_result!(crate::sys::napi_has_own_property(
+            self.0,
+            object,
+            key,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// delete property of object, return false if property can't be deleted
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn delete_property(&self, object: napi_value, key: napi_value) -> Result<bool, NjError> {
+        let mut result: bool = false;
+        napi_call_result!(crate::sys::napi_delete_property(
+            self.0,
+            object,
+            key,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// get prototype of object, same as `Object.getPrototypeOf`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_prototype(&self, object: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_prototype(self.0, object, &mut result))?;
+        Ok(result)
+    }
+
+    /// same as `Object.freeze`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn object_freeze(&self, object: napi_value) -> Result<(), NjError> {
+        napi_call_result!(crate::sys::napi_object_freeze(self.0, object))
+    }
+
+    /// same as `Object.seal`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn object_seal(&self, object: napi_value) -> Result<(), NjError> {
+        napi_call_result!(crate::sys::napi_object_seal(self.0, object))
+    }
+
     /// check if napi value is array
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn is_array(&self, array: napi_value) -> Result<bool, NjError> {
@@ -202,6 +581,96 @@ impl JsEnv {
         Ok(result)
     }
 
+    /// check if napi value is typed array
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn is_typedarray(&self, n_value: napi_value) -> Result<bool, NjError> {
+        let mut result: bool = false;
+
+        napi_call_result!(crate::sys::napi_is_typedarray(self.0, n_value, &mut result))?;
+
+        Ok(result)
+    }
+
+    /// get typed array info: element type, number of elements and pointer to first element
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_typedarray_info(
+        &self,
+        n_value: napi_value,
+    ) -> Result<(napi_typedarray_type, usize, *mut ::std::os::raw::c_void), NjError> {
+        let mut array_type: napi_typedarray_type = 0;
+        let mut length = 0_usize;
+        let mut data = ptr::null_mut();
+
+        napi_call_result!(crate::sys::napi_get_typedarray_info(
+            self.0,
+            n_value,
+            &mut array_type,
+            &mut length,
+            &mut data,
+            ptr::null_mut(),
+            ptr::null_mut()
+        ))?;
+
+        Ok((array_type, length, data))
+    }
+
+    /// get data pointer and byte length of array buffer
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_arraybuffer_info(
+        &self,
+        n_value: napi_value,
+    ) -> Result<(*mut ::std::os::raw::c_void, usize), NjError> {
+        let mut data = ptr::null_mut();
+        let mut byte_length = 0_usize;
+
+        napi_call_result!(crate::sys::napi_get_arraybuffer_info(
+            self.0,
+            n_value,
+            &mut data,
+            &mut byte_length
+        ))?;
+
+        Ok((data, byte_length))
+    }
+
+    /// check if napi value is data view
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn is_dataview(&self, n_value: napi_value) -> Result<bool, NjError> {
+        let mut result: bool = false;
+
+        napi_call_result!(crate::sys::napi_is_dataview(self.0, n_value, &mut result))?;
+
+        Ok(result)
+    }
+
+    /// get data view info: byte window of underlying array buffer and its offset
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_dataview_info(&self, n_value: napi_value) -> Result<(&[u8], usize), NjError> {
+        use std::slice;
+
+        let mut byte_length = 0_usize;
+        let mut data = ptr::null_mut();
+        let mut byte_offset = 0_usize;
+
+        napi_call_result!(crate::sys::napi_get_dataview_info(
+            self.0,
+            n_value,
+            &mut byte_length,
+            &mut data,
+            ptr::null_mut(),
+            &mut byte_offset
+        ))?;
+
+        // data can be null for empty view
+        if byte_length == 0 {
+            return Ok((&[], byte_offset));
+        }
+
+        let window: &[u8] = unsafe { slice::from_raw_parts(data as *const u8, byte_length) };
+
+        Ok((window, byte_offset))
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn is_date(&self, n_value: napi_value) -> Result<bool, NjError> {
         let mut result: bool = false;
@@ -211,6 +680,75 @@ impl JsEnv {
         Ok(result)
     }
 
+    /// convert any js value to string, same as `String(value)`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn coerce_to_string(&self, js_value: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_coerce_to_string(
+            self.0,
+            js_value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// convert any js value to number, same as `Number(value)`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn coerce_to_number(&self, js_value: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_coerce_to_number(
+            self.0,
+            js_value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// convert any js value to boolean, same as `Boolean(value)`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn coerce_to_bool(&self, js_value: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_coerce_to_bool(
+            self.0,
+            js_value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// convert any js value to object, same as `Object(value)` except null and undefined throw
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn coerce_to_object(&self, js_value: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_coerce_to_object(
+            self.0,
+            js_value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// create js date from milliseconds since unix epoch
+    pub fn create_date(&self, time: f64) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_date(self.0, time, &mut result))?;
+        Ok(result)
+    }
+
+    /// get milliseconds since unix epoch of js date
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_date_value(&self, n_value: napi_value) -> Result<f64, NjError> {
+        let mut result: f64 = 0.0;
+
+        napi_call_result!(crate::sys::napi_get_date_value(
+            self.0,
+            n_value,
+            &mut result
+        ))?;
+
+        Ok(result)
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn is_error(&self, n_value: napi_value) -> Result<bool, NjError> {
         let mut result: bool = false;
@@ -228,6 +766,25 @@ impl JsEnv {
         Ok(js_global)
     }
 
+    /// check if object is instance of constructor, same as js `instanceof`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn instance_of(
+        &self,
+        object: napi_value,
+        constructor: napi_value,
+    ) -> Result<bool, NjError> {
+        let mut result: bool = false;
+
+        napi_call_result!(crate::sys::napi_instanceof(
+            self.0,
+            object,
+            constructor,
+            &mut result
+        ))?;
+
+        Ok(result)
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn call_function(
         &self,
@@ -281,6 +838,62 @@ impl JsEnv {
         Ok(JsCallback::new(JsEnv::new(self.0), this, args))
     }
 
+    /// get data pointer passed when function was created
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_cb_data(
+        &self,
+        info: napi_callback_info,
+    ) -> Result<*mut ::std::os::raw::c_void, NjError> {
+        let mut data = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_cb_info(
+            self.0,
+            info,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            &mut data
+        ))?;
+        Ok(data)
+    }
+
+    /// create js function calling native callback, data can be retrieved with `get_cb_data`
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn create_function(
+        &self,
+        name: &str,
+        callback: napi_callback_raw,
+        data: *mut ::std::os::raw::c_void,
+    ) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_function(
+            self.0,
+            name.as_ptr() as *const ::std::os::raw::c_char,
+            name.len(),
+            Some(callback),
+            data,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// call finalize with data when object is garbage collected
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn add_finalizer(
+        &self,
+        js_object: napi_value,
+        data: *mut ::std::os::raw::c_void,
+        finalize: napi_finalize_raw,
+    ) -> Result<(), NjError> {
+        napi_call_result!(crate::sys::napi_add_finalizer(
+            self.0,
+            js_object,
+            data,
+            Some(finalize),
+            ptr::null_mut(),
+            ptr::null_mut()
+        ))
+    }
+
     /// define classes
     #[instrument(skip(properties))]
     pub fn define_class(
@@ -306,6 +919,22 @@ impl JsEnv {
         Ok(js_constructor)
     }
 
+    /// open handle scope, values created until guard is dropped are released when it is dropped
+    pub fn open_handle_scope(&self) -> Result<HandleScope, NjError> {
+        let mut scope = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_open_handle_scope(self.0, &mut scope))?;
+        Ok(HandleScope { env: *self, scope })
+    }
+
+    /// open handle scope from which single value can be escaped to outer scope
+    pub fn open_escapable_handle_scope(&self) -> Result<EscapableHandleScope, NjError> {
+        let mut scope = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_open_escapable_handle_scope(
+            self.0, &mut scope
+        ))?;
+        Ok(EscapableHandleScope { env: *self, scope })
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     pub fn create_reference(&self, cons: napi_value, count: u32) -> Result<napi_ref, NjError> {
         let mut result = ptr::null_mut();
@@ -326,6 +955,22 @@ impl JsEnv {
         ))?)
     }
 
+    /// increase reference count, return new count
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn reference_ref(&self, ref_: napi_ref) -> Result<u32, NjError> {
+        let mut count = 0;
+        napi_call_result!(crate::sys::napi_reference_ref(self.0, ref_, &mut count))?;
+        Ok(count)
+    }
+
+    /// decrease reference count, return new count
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn reference_unref(&self, ref_: napi_ref) -> Result<u32, NjError> {
+        let mut count = 0;
+        napi_call_result!(crate::sys::napi_reference_unref(self.0, ref_, &mut count))?;
+        Ok(count)
+    }
+
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     #[instrument]
     pub fn get_new_target(&self, info: napi_callback_info) -> Result<napi_value, NjError> {
@@ -358,9 +1003,92 @@ impl JsEnv {
         Ok(result)
     }
 
+    /// create js external value holding raw pointer, finalize is called when value is garbage collected
+    pub fn create_external(
+        &self,
+        data: *mut u8,
+        finalize: napi_finalize_raw,
+    ) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+
+        napi_call_result!(crate::sys::napi_create_external(
+            self.0,
+            data as *mut core::ffi::c_void,
+            Some(finalize),
+            ptr::null_mut(),
+            &mut result
+        ))?;
+
+        Ok(result)
+    }
+
+    /// get raw pointer stored in js external value
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn get_value_external(&self, js_value: napi_value) -> Result<*mut u8, NjError> {
+        let mut result: *mut ::std::os::raw::c_void = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_value_external(
+            self.0,
+            js_value,
+            &mut result
+        ))?;
+        Ok(result as *mut u8)
+    }
+
+    /// associate type tag with object or external, tag can be set only once
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn type_tag_object(
+        &self,
+        js_value: napi_value,
+        tag: &napi_type_tag,
+    ) -> Result<(), NjError> {
+        napi_call_result!(crate::sys::napi_type_tag_object(self.0, js_value, tag))
+    }
+
+    /// check if object or external was tagged with type tag
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn check_object_type_tag(
+        &self,
+        js_value: napi_value,
+        tag: &napi_type_tag,
+    ) -> Result<bool, NjError> {
+        let mut result = false;
+        napi_call_result!(crate::sys::napi_check_object_type_tag(
+            self.0,
+            js_value,
+            tag,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+
+    /// check instance was tagged by class `T`
+    fn assert_class_instance<T: JSClass>(&self, js_this: napi_value) -> Result<(), NjError> {
+        let value_type = self.value_type(js_this)?;
+        if value_type != crate::sys::napi_valuetype_napi_object
+            && value_type != crate::sys::napi_valuetype_napi_function
+        {
+            return Err(NjError::InvalidType(
+                T::CLASS_NAME.to_owned(),
+                self.value_type_string(js_this)?.to_owned(),
+            ));
+        }
+
+        if self.check_object_type_tag(js_this, &T::type_tag())? {
+            Ok(())
+        } else {
+            Err(NjError::InvalidType(
+                T::CLASS_NAME.to_owned(),
+                "object of other class".to_owned(),
+            ))
+        }
+    }
+
+    /// get rust object wrapped in instance of class `T`
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     #[instrument]
-    pub fn unwrap<T>(&self, js_this: napi_value) -> Result<&'static T, NjError> {
+    pub fn unwrap<T: JSClass>(&self, js_this: napi_value) -> Result<&'static T, NjError> {
+        self.assert_class_instance::<T>(js_this)?;
+
         let mut result: *mut ::std::os::raw::c_void = ptr::null_mut();
         napi_call_result!(crate::sys::napi_unwrap(self.0, js_this, &mut result))?;
 
@@ -369,14 +1097,17 @@ impl JsEnv {
             if result.is_null() {
                 return Err(NjError::Other("unwrap got null pointer".to_string()));
             }
-            let rust_ref: &T = &mut *(result as *mut T);
-            rust_ref
+            let rust_ref: &JSObjectWrapper<T> = &*(result as *mut JSObjectWrapper<T>);
+            rust_ref.inner()
         })
     }
 
+    /// get rust object wrapped in instance of class `T` mutably
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
     #[instrument]
-    pub fn unwrap_mut<T>(&self, js_this: napi_value) -> Result<&'static mut T, NjError> {
+    pub fn unwrap_mut<T: JSClass>(&self, js_this: napi_value) -> Result<&'static mut T, NjError> {
+        self.assert_class_instance::<T>(js_this)?;
+
         let mut result: *mut ::std::os::raw::c_void = ptr::null_mut();
         debug!(env = ?self.0,"napi unwrap");
         napi_call_result!(crate::sys::napi_unwrap(self.0, js_this, &mut result))?;
@@ -385,9 +1116,9 @@ impl JsEnv {
             if result.is_null() {
                 return Err(NjError::Other("unwrap mut null pointer".to_string()));
             }
-            let ptr = result as *mut T;
-            let rust_ref: &mut T = &mut *(ptr);
-            rust_ref
+            let ptr = result as *mut JSObjectWrapper<T>;
+            let rust_ref: &mut JSObjectWrapper<T> = &mut *(ptr);
+            rust_ref.mut_inner()
         })
     }
 
@@ -527,18 +1258,50 @@ impl JsEnv {
             return;
         }
 
-        let c_error_msg = CString::new(message).expect("message should not contain null");
-        unsafe {
-            crate::sys::napi_throw_type_error(self.inner(), ptr::null_mut(), c_error_msg.as_ptr())
-        };
+        let c_error_msg = CString::new(message).expect("message should not contain null");
+        unsafe {
+            crate::sys::napi_throw_type_error(self.inner(), ptr::null_mut(), c_error_msg.as_ptr())
+        };
+    }
+
+    pub fn throw_range_error(&self, message: &str) {
+        debug!(message, "range error");
+        // check if there is exception pending, if so log and not do anything
+        if self.is_exception_pending() {
+            error!(
+                "there is exception pending when trying to throw {}, ignoring for now",
+                message
+            );
+            return;
+        }
+
+        let c_error_msg = CString::new(message).expect("message should not contain null");
+        unsafe {
+            crate::sys::napi_throw_range_error(self.inner(), ptr::null_mut(), c_error_msg.as_ptr())
+        };
+    }
+
+    pub fn create_error(&self, message: &str) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+
+        let err_message = self.create_string_utf8(message)?;
+
+        napi_call_result!(crate::sys::napi_create_error(
+            self.0,
+            ptr::null_mut(),
+            err_message,
+            &mut result
+        ))?;
+
+        Ok(result)
     }
 
-    pub fn create_error(&self, message: &str) -> Result<napi_value, NjError> {
+    pub fn create_range_error(&self, message: &str) -> Result<napi_value, NjError> {
         let mut result = ptr::null_mut();
 
         let err_message = self.create_string_utf8(message)?;
 
-        napi_call_result!(crate::sys::napi_create_error(
+        napi_call_result!(crate::sys::napi_create_range_error(
             self.0,
             ptr::null_mut(),
             err_message,
@@ -680,6 +1443,179 @@ impl JsEnv {
 
         Ok(())
     }
+
+    /// run closure when env is torn down, such as when worker thread or process exits.
+    /// JS can't be called from closure
+    pub fn on_cleanup<F>(&self, func: F) -> Result<CleanupHook, NjError>
+    where
+        F: FnOnce() + 'static,
+    {
+        CleanupHook::add(self, Box::new(func))
+    }
+
+    /// run closure when env is torn down, env exits only after `done` is called on passed token.
+    /// use it to finish asynchronous work such as closing sockets before exit
+    pub fn on_async_cleanup<F>(&self, func: F) -> Result<AsyncCleanupHook, NjError>
+    where
+        F: FnOnce(AsyncCleanupDone) + 'static,
+    {
+        AsyncCleanupHook::add(self, Box::new(func))
+    }
+
+    /// get values stored for this env, created on first use.
+    /// map is owned by napi instance data of env, only shared references to it are created
+    fn instance_data_map(&self) -> Result<&InstanceData, NjError> {
+        let mut data = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_get_instance_data(self.0, &mut data))?;
+        if !data.is_null() {
+            return Ok(unsafe { &*(data as *const InstanceData) });
+        }
+
+        let map = Box::into_raw(Box::<InstanceData>::default());
+        if let Err(err) = napi_call_result!(crate::sys::napi_set_instance_data(
+            self.0,
+            map as *mut ::std::os::raw::c_void,
+            Some(InstanceData::finalize),
+            ptr::null_mut()
+        )) {
+            drop(unsafe { Box::from_raw(map) });
+            return Err(err);
+        }
+        Ok(unsafe { &*map })
+    }
+
+    /// store value for this env, such as addon state which must not be shared between worker threads.
+    /// one value can be stored for each type, it is dropped when env is torn down.
+    /// use interior mutability such as `RefCell` for state which changes.
+    ///
+    /// Values are kept in napi instance data slot of env, which has room for single pointer.
+    /// node-bindgen owns that slot: calling `napi_set_instance_data` from `sys` directly replaces
+    /// all values stored here, store data through this method instead.
+    pub fn set_instance_data<T: 'static>(&self, data: T) -> Result<(), NjError> {
+        let rejected = {
+            let mut values = self.instance_data_map()?.values.borrow_mut();
+            match values.entry(TypeId::of::<T>()) {
+                Entry::Occupied(_) => Some(data),
+                Entry::Vacant(entry) => {
+                    entry.insert(Box::new(data));
+                    None
+                }
+            }
+        };
+
+        // rejected data is dropped after borrow ends, its drop may access instance data
+        match rejected {
+            Some(data) => {
+                drop(data);
+                Err(NjError::Other(format!(
+                    "instance data of type {} is already set",
+                    std::any::type_name::<T>()
+                )))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// get value stored for this env with `set_instance_data`
+    pub fn instance_data<T: 'static>(&self) -> Result<Option<&T>, NjError> {
+        let values = self.instance_data_map()?.values.borrow();
+        Ok(values
+            .get(&TypeId::of::<T>())
+            .and_then(|data| data.downcast_ref::<T>())
+            // SAFETY: values are boxed and never removed until env is torn down,
+            // so they stay at same address when map grows and outlive this env handle
+            .map(|data| unsafe { &*(data as *const T) }))
+    }
+}
+
+/// values stored per env by type, set as napi instance data
+#[derive(Default)]
+struct InstanceData {
+    values: RefCell<HashMap<TypeId, Box<dyn Any>>>,
+}
+
+impl InstanceData {
+    extern "C" fn finalize(
+        _env: napi_env,
+        finalize_data: *mut ::std::os::raw::c_void,
+        _finalize_hint: *mut ::std::os::raw::c_void,
+    ) {
+        debug!("instance data finalize");
+        unsafe {
+            drop(Box::from_raw(finalize_data as *mut InstanceData));
+        }
+    }
+}
+
+/// guard for handle scope opened by [`JsEnv::open_handle_scope`].
+///
+/// Values created while guard is alive must not be used after it is dropped,
+/// unless they are stored in object outside of scope or referenced.
+/// Guards must be dropped in reverse order of opening.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::JsEnv;
+/// use node_bindgen::sys::napi_value;
+///
+/// fn fill(env: &JsEnv, array: napi_value, count: usize) -> Result<(), NjError> {
+///     for i in 0..count {
+///         let _scope = env.open_handle_scope()?;
+///         let value = env.create_string_utf8(&i.to_string())?;
+///         env.set_element(array, value, i)?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct HandleScope {
+    env: JsEnv,
+    scope: napi_handle_scope,
+}
+
+impl Drop for HandleScope {
+    fn drop(&mut self) {
+        if let Err(err) =
+            napi_call_result!(crate::sys::napi_close_handle_scope(self.env.0, self.scope))
+        {
+            error!("handle scope can't be closed: {}", err);
+        }
+    }
+}
+
+/// guard for handle scope opened by [`JsEnv::open_escapable_handle_scope`]
+#[derive(Debug)]
+pub struct EscapableHandleScope {
+    env: JsEnv,
+    scope: napi_escapable_handle_scope,
+}
+
+impl EscapableHandleScope {
+    /// promote value to outer scope so it stays valid after guard is dropped,
+    /// only one value can be escaped
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    pub fn escape(&self, value: napi_value) -> Result<napi_value, NjError> {
+        let mut result = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_escape_handle(
+            self.env.0,
+            self.scope,
+            value,
+            &mut result
+        ))?;
+        Ok(result)
+    }
+}
+
+impl Drop for EscapableHandleScope {
+    fn drop(&mut self) {
+        if let Err(err) = napi_call_result!(crate::sys::napi_close_escapable_handle_scope(
+            self.env.0, self.scope
+        )) {
+            error!("handle scope can't be closed: {}", err);
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -687,6 +1623,22 @@ pub struct JsCallback {
     env: JsEnv,
     this: napi_value,
     args: VecDeque<napi_value>,
+    // memory ranges of arguments borrowed during this call
+    borrows: RefCell<Vec<BytesBorrow>>,
+}
+
+/// memory range of argument viewed as bytes, see [`JsCallback::borrow_bytes_mut`]
+#[derive(Clone, Debug)]
+struct BytesBorrow {
+    start: usize,
+    end: usize,
+    mutable: bool,
+}
+
+impl BytesBorrow {
+    fn overlaps(&self, start: usize, end: usize) -> bool {
+        start < self.end && self.start < end
+    }
 }
 
 unsafe impl Send for JsCallback {}
@@ -698,6 +1650,7 @@ impl JsCallback {
             env,
             this,
             args: args.into(),
+            borrows: RefCell::new(vec![]),
         }
     }
 
@@ -709,6 +1662,84 @@ impl JsCallback {
         self.args[index]
     }
 
+    /// get argument at index, fails if not enough arguments are passed
+    pub fn arg_at(&self, index: usize) -> Result<napi_value, NjError> {
+        self.args.get(index).copied().ok_or_else(|| {
+            NjError::Other(format!(
+                "trying to get arg at: {} but only {} args passed",
+                index,
+                self.args.len()
+            ))
+        })
+    }
+
+    /// borrow argument memory mutably for rest of the call,
+    /// fails if memory overlaps with other argument already borrowed
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) fn borrow_bytes_mut(&self, data: *mut u8, len: usize) -> Result<&mut [u8], NjError> {
+        // data can be null for empty buffer
+        if len == 0 {
+            return Ok(&mut []);
+        }
+
+        let start = data as usize;
+        let end = start + len;
+
+        let mut borrows = self.borrows.borrow_mut();
+        if borrows.iter().any(|borrow| borrow.overlaps(start, end)) {
+            return Err(NjError::Other(
+                "buffer memory is already borrowed by another argument".to_owned(),
+            ));
+        }
+        borrows.push(BytesBorrow {
+            start,
+            end,
+            mutable: true,
+        });
+
+        // memory is kept alive by argument for duration of call and no other view is handed out
+        Ok(unsafe { std::slice::from_raw_parts_mut(data, len) })
+    }
+
+    /// record argument memory viewed as shared slice for rest of the call,
+    /// fails if memory overlaps with other argument borrowed mutably
+    fn borrow_bytes(&self, data: *const u8, len: usize) -> Result<(), NjError> {
+        if len == 0 {
+            return Ok(());
+        }
+
+        let start = data as usize;
+        let end = start + len;
+
+        let mut borrows = self.borrows.borrow_mut();
+        if borrows
+            .iter()
+            .any(|borrow| borrow.mutable && borrow.overlaps(start, end))
+        {
+            return Err(NjError::Other(
+                "buffer memory is already borrowed mutably by another argument".to_owned(),
+            ));
+        }
+        borrows.push(BytesBorrow {
+            start,
+            end,
+            mutable: false,
+        });
+        Ok(())
+    }
+
+    /// convert argument and record memory it views
+    fn convert_arg<'a, T>(&'a self, js_value: napi_value) -> Result<T, NjError>
+    where
+        T: JSValue<'a>,
+    {
+        let value = T::convert_to_rust(self.env(), js_value)?;
+        if let Some((data, len)) = value.viewed_bytes() {
+            self.borrow_bytes(data, len)?;
+        }
+        Ok(value)
+    }
+
     pub fn this(&self) -> napi_value {
         self.this
     }
@@ -769,16 +1800,14 @@ impl JsCallback {
         }
     }
 
+    /// get rust object wrapped in `this`, error if `this` is not instance of class `T`
     #[instrument]
-    pub fn unwrap_mut<T>(&self) -> Result<&'static mut T, NjError> {
-        Ok(self
-            .env
-            .unwrap_mut::<JSObjectWrapper<T>>(self.this())?
-            .mut_inner())
+    pub fn unwrap_mut<T: JSClass>(&self) -> Result<&'static mut T, NjError> {
+        self.env.unwrap_mut::<T>(self.this())
     }
 
-    pub fn unwrap<T>(&self) -> Result<&'static T, NjError> {
-        Ok(self.env.unwrap::<JSObjectWrapper<T>>(self.this())?.inner())
+    pub fn unwrap<T: JSClass>(&self) -> Result<&'static T, NjError> {
+        self.env.unwrap::<T>(self.this())
     }
 }
 
@@ -861,15 +1890,7 @@ where
             ty = std::any::type_name::<T>(),
             "extract from ExtractArgFromJs"
         );
-        if index < js_cb.args.len() {
-            T::convert_to_rust(js_cb.env(), js_cb.args[index])
-        } else {
-            Err(NjError::Other(format!(
-                "trying to get arg at: {} but only {} args passed",
-                index,
-                js_cb.args.len()
-            )))
-        }
+        js_cb.convert_arg(js_cb.arg_at(index)?)
     }
 }
 
@@ -886,7 +1907,7 @@ where
             if js_cb.env().is_undefined_or_null(js_cb.args[index])? {
                 Ok(None)
             } else {
-                Ok(Some(T::convert_to_rust(js_cb.env(), js_cb.args[index])?))
+                Ok(Some(js_cb.convert_arg(js_cb.args[index])?))
             }
         } else {
             Ok(None)
@@ -996,6 +2017,71 @@ impl JsCallbackFunction {
     }
 }
 
+/// value which can be used as key of js object property
+pub trait PropertyKey {
+    fn to_property_key(&self, env: &JsEnv) -> Result<napi_value, NjError>;
+}
+
+impl PropertyKey for str {
+    fn to_property_key(&self, env: &JsEnv) -> Result<napi_value, NjError> {
+        env.create_string_utf8(self)
+    }
+}
+
+impl PropertyKey for String {
+    fn to_property_key(&self, env: &JsEnv) -> Result<napi_value, NjError> {
+        env.create_string_utf8(self)
+    }
+}
+
+/// napi value which is already string or symbol
+impl PropertyKey for napi_value {
+    fn to_property_key(&self, _env: &JsEnv) -> Result<napi_value, NjError> {
+        Ok(*self)
+    }
+}
+
+/// which keys are collected by [`JsObject::property_keys`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCollectionMode {
+    /// keys of object and its prototype chain
+    IncludePrototypes,
+    /// keys of object only
+    OwnOnly,
+}
+
+impl From<KeyCollectionMode> for napi_key_collection_mode {
+    fn from(mode: KeyCollectionMode) -> Self {
+        match mode {
+            KeyCollectionMode::IncludePrototypes => {
+                crate::sys::napi_key_collection_mode_napi_key_include_prototypes
+            }
+            KeyCollectionMode::OwnOnly => crate::sys::napi_key_collection_mode_napi_key_own_only,
+        }
+    }
+}
+
+/// filter for [`JsObject::property_keys`], filters can be combined with `|`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyFilter(napi_key_filter);
+
+impl KeyFilter {
+    pub const ALL_PROPERTIES: Self = Self(crate::sys::napi_key_filter_napi_key_all_properties);
+    pub const WRITABLE: Self = Self(crate::sys::napi_key_filter_napi_key_writable);
+    pub const ENUMERABLE: Self = Self(crate::sys::napi_key_filter_napi_key_enumerable);
+    pub const CONFIGURABLE: Self = Self(crate::sys::napi_key_filter_napi_key_configurable);
+    pub const SKIP_STRINGS: Self = Self(crate::sys::napi_key_filter_napi_key_skip_strings);
+    pub const SKIP_SYMBOLS: Self = Self(crate::sys::napi_key_filter_napi_key_skip_symbols);
+}
+
+impl std::ops::BitOr for KeyFilter {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
 /// represent arbitrary js object
 pub struct JsObject {
     env: JsEnv,
@@ -1023,19 +2109,14 @@ impl JsObject {
         self.napi_value
     }
 
-    /// get property
-    pub fn get_property(&self, key: &str) -> Result<Option<Self>, NjError> {
-        let property_key = self.env.create_string_utf8(key)?;
-
-        let mut exist: bool = false;
-        napi_call_result!(napi_has_property(
-            self.env.inner(),
-            self.napi_value,
-            property_key,
-            &mut exist,
-        ))?;
+    /// get property, key can be string or symbol
+    pub fn get_property<K>(&self, key: &K) -> Result<Option<Self>, NjError>
+    where
+        K: PropertyKey + ?Sized,
+    {
+        let property_key = key.to_property_key(&self.env)?;
 
-        if !exist {
+        if !self.env.has_property(self.napi_value, property_key)? {
             return Ok(None);
         }
 
@@ -1055,19 +2136,126 @@ impl JsObject {
     }
 
     #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    pub fn set_property(&mut self, key: &str, property_value: napi_value) -> Result<(), NjError> {
-        use crate::sys::napi_set_property;
+    pub fn set_property<K>(&mut self, key: &K, property_value: napi_value) -> Result<(), NjError>
+    where
+        K: PropertyKey + ?Sized,
+    {
+        let property_key = key.to_property_key(&self.env)?;
 
-        let property_key = self.env.create_string_utf8(key)?;
+        self.env
+            .set_property(self.napi_value, property_key, property_value)
+    }
 
-        napi_call_result!(napi_set_property(
-            self.env.inner(),
+    /// check if object or its prototype chain has property
+    pub fn has_property<K>(&self, key: &K) -> Result<bool, NjError>
+    where
+        K: PropertyKey + ?Sized,
+    {
+        self.env
+            .has_property(self.napi_value, key.to_property_key(&self.env)?)
+    }
+
+    /// get property converted to rust value, `None` if property is missing or undefined
+    pub fn get<'a, T, K>(&'a self, key: &K) -> Result<Option<T>, NjError>
+    where
+        T: JSValue<'a>,
+        K: PropertyKey + ?Sized,
+    {
+        let value = self
+            .env
+            .get_property(self.napi_value, key.to_property_key(&self.env)?)?;
+
+        if self.env.value_type(value)? == crate::sys::napi_valuetype_napi_undefined {
+            Ok(None)
+        } else {
+            T::convert_to_rust(&self.env, value).map(Some)
+        }
+    }
+
+    /// check if object itself has property, prototype chain is not checked
+    pub fn has_own_property<K>(&self, key: &K) -> Result<bool, NjError>
+    where
+        K: PropertyKey + ?Sized,
+    {
+        self.env
+            .has_own_property(self.napi_value, key.to_property_key(&self.env)?)
+    }
+
+    /// delete property, return false if property is not configurable
+    pub fn delete_property<K>(&mut self, key: &K) -> Result<bool, NjError>
+    where
+        K: PropertyKey + ?Sized,
+    {
+        self.env
+            .delete_property(self.napi_value, key.to_property_key(&self.env)?)
+    }
+
+    /// property keys selected by mode and filter, keys are strings or symbols.
+    /// numeric keys such as array indices are converted to strings
+    pub fn property_keys(
+        &self,
+        mode: KeyCollectionMode,
+        filter: KeyFilter,
+    ) -> Result<Vec<Self>, NjError> {
+        let keys = self.env.get_all_property_names(
             self.napi_value,
-            property_key,
-            property_value,
-        ))?;
+            mode.into(),
+            filter.0,
+            crate::sys::napi_key_conversion_napi_key_numbers_to_strings,
+        )?;
 
-        Ok(())
+        (0..self.env.get_array_length(keys)?)
+            .map(|i| Ok(Self::new(self.env, self.env.get_element(keys, i)?)))
+            .collect()
+    }
+
+    /// own enumerable string keys, same as `Object.keys`
+    pub fn keys(&self) -> Result<Vec<String>, NjError> {
+        self.property_keys(
+            KeyCollectionMode::OwnOnly,
+            KeyFilter::ENUMERABLE | KeyFilter::SKIP_SYMBOLS,
+        )?
+        .iter()
+        .map(|key| key.as_value())
+        .collect()
+    }
+
+    /// own enumerable string keyed properties converted to rust values, same as `Object.entries`
+    pub fn entries<'a, T>(&'a self) -> Result<Vec<(String, T)>, NjError>
+    where
+        T: JSValue<'a>,
+    {
+        self.keys()?
+            .into_iter()
+            .map(|key| {
+                let value = self
+                    .env
+                    .get_property(self.napi_value, key.to_property_key(&self.env)?)?;
+                let value = T::convert_to_rust(&self.env, value)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+
+    /// prototype of object, `None` if prototype is null
+    pub fn prototype(&self) -> Result<Option<Self>, NjError> {
+        let prototype = self.env.get_prototype(self.napi_value)?;
+
+        if self.env.value_type(prototype)? == crate::sys::napi_valuetype_napi_null {
+            Ok(None)
+        } else {
+            Ok(Some(Self::new(self.env, prototype)))
+        }
+    }
+
+    /// prevent adding, removing and changing properties, same as `Object.freeze`
+    pub fn freeze(&mut self) -> Result<(), NjError> {
+        self.env.object_freeze(self.napi_value)
+    }
+
+    /// prevent adding and removing properties, same as `Object.seal`
+    pub fn seal(&mut self) -> Result<(), NjError> {
+        self.env.object_seal(self.napi_value)
     }
 
     /// convert to equivalent rust object
@@ -1091,3 +2279,167 @@ impl TryIntoJs for JsObject {
         Ok(self.napi_value)
     }
 }
+
+/// handle to js array, elements are converted only when accessed
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::JsArray;
+///
+/// #[node_bindgen]
+/// fn first_negative(array: JsArray) -> Result<Option<u32>, NjError> {
+///     for (i, value) in array.iter::<f64>()?.enumerate() {
+///         if value? < 0.0 {
+///             return Ok(Some(i as u32));
+///         }
+///     }
+///     Ok(None)
+/// }
+/// ```
+pub struct JsArray {
+    env: JsEnv,
+    napi_value: napi_value,
+}
+
+unsafe impl Send for JsArray {}
+
+impl JsArray {
+    pub fn new(env: JsEnv, napi_value: napi_value) -> Self {
+        Self { env, napi_value }
+    }
+
+    /// create new empty js array
+    pub fn create(env: &JsEnv) -> Result<Self, NjError> {
+        Ok(Self::new(*env, env.create_array_with_len(0)?))
+    }
+
+    pub fn env(&self) -> &JsEnv {
+        &self.env
+    }
+
+    pub fn napi_value(&self) -> napi_value {
+        self.napi_value
+    }
+
+    pub fn len(&self) -> Result<u32, NjError> {
+        self.env.get_array_length(self.napi_value)
+    }
+
+    pub fn is_empty(&self) -> Result<bool, NjError> {
+        Ok(self.len()? == 0)
+    }
+
+    /// get element converted to rust value, `None` if element is missing or undefined
+    pub fn get<'a, T>(&'a self, index: u32) -> Result<Option<T>, NjError>
+    where
+        T: JSValue<'a>,
+    {
+        let element = self.env.get_element(self.napi_value, index)?;
+
+        if self.env.value_type(element)? == crate::sys::napi_valuetype_napi_undefined {
+            Ok(None)
+        } else {
+            T::convert_to_rust(&self.env, element).map(Some)
+        }
+    }
+
+    /// set element, array grows if index is beyond length
+    pub fn set<T>(&mut self, index: u32, value: T) -> Result<(), NjError>
+    where
+        T: TryIntoJs,
+    {
+        let element = value.try_to_js(&self.env)?;
+        self.env
+            .set_element(self.napi_value, element, index as usize)
+    }
+
+    /// append element at end of array
+    pub fn push<T>(&mut self, value: T) -> Result<(), NjError>
+    where
+        T: TryIntoJs,
+    {
+        let len = self.len()?;
+        self.set(len, value)
+    }
+
+    /// false if index is beyond length or element is hole
+    pub fn has_element(&self, index: u32) -> Result<bool, NjError> {
+        self.env.has_element(self.napi_value, index)
+    }
+
+    /// delete element leaving hole, length is not changed
+    pub fn delete_element(&mut self, index: u32) -> Result<bool, NjError> {
+        self.env.delete_element(self.napi_value, index)
+    }
+
+    /// iterate elements converted to rust values, up to length at time of call.
+    /// holes and undefined are passed to `T` as undefined
+    pub fn iter<'a, T>(&'a self) -> Result<JsArrayIter<'a, T>, NjError>
+    where
+        T: JSValue<'a>,
+    {
+        Ok(JsArrayIter {
+            array: self,
+            index: 0,
+            len: self.len()?,
+            _marker: std::marker::PhantomData,
+        })
+    }
+}
+
+impl JSValue<'_> for JsArray {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        if !env.is_array(js_value)? {
+            return Err(NjError::InvalidType(
+                "array".to_owned(),
+                env.value_type_string(js_value)?.to_owned(),
+            ));
+        }
+
+        Ok(Self::new(*env, js_value))
+    }
+}
+
+impl TryIntoJs for JsArray {
+    fn try_to_js(self, _js_env: &JsEnv) -> Result<napi_value, NjError> {
+        Ok(self.napi_value)
+    }
+}
+
+/// iterator created by [`JsArray::iter`]
+pub struct JsArrayIter<'a, T> {
+    array: &'a JsArray,
+    index: u32,
+    len: u32,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<'a, T> Iterator for JsArrayIter<'a, T>
+where
+    T: JSValue<'a>,
+{
+    type Item = Result<T, NjError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+
+        let index = self.index;
+        self.index += 1;
+
+        let env = &self.array.env;
+        Some(
+            env.get_element(self.array.napi_value, index)
+                .and_then(|element| T::convert_to_rust(env, element)),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.len - self.index) as usize;
+        (remaining, Some(remaining))
+    }
+}
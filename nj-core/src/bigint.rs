@@ -114,3 +114,169 @@ impl TryIntoJs for BigInt {
         Ok(napi_buffer)
     }
 }
+
+/// largest integer which js number can represent exactly, `Number.MAX_SAFE_INTEGER`
+const MAX_SAFE_INTEGER: i64 = (1 << 53) - 1;
+
+/// read js number which must be safe integer
+fn safe_integer(env: &JsEnv, js_value: napi_value) -> Result<i64, NjError> {
+    let value: f64 = env.convert_to_rust(js_value)?;
+
+    // fract of NaN and infinity is NaN
+    if value.fract() != 0.0 {
+        return Err(NjError::InvalidType(
+            "integer".to_owned(),
+            format!("number {value}"),
+        ));
+    }
+
+    if value.abs() > MAX_SAFE_INTEGER as f64 {
+        return Err(NjError::OutOfRange(
+            "safe integer".to_owned(),
+            value.to_string(),
+        ));
+    }
+
+    Ok(value as i64)
+}
+
+/// check value is number or BigInt, returns true if BigInt
+fn is_bigint(env: &JsEnv, js_value: napi_value) -> Result<bool, NjError> {
+    match env.value_type(js_value)? {
+        crate::sys::napi_valuetype_napi_bigint => Ok(true),
+        crate::sys::napi_valuetype_napi_number => Ok(false),
+        _ => Err(NjError::InvalidType(
+            "number or big_int".to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        )),
+    }
+}
+
+/// error for BigInt which doesn't fit into rust type
+fn bigint_out_of_range<T>(env: &JsEnv, js_value: napi_value) -> NjError {
+    let value = match BigInt::convert_to_rust(env, js_value) {
+        Ok(value) => value,
+        Err(err) => return err,
+    };
+    out_of_range::<T>(value)
+}
+
+/// error for integer which doesn't fit into rust type
+fn out_of_range<T>(value: impl std::fmt::Display) -> NjError {
+    NjError::OutOfRange(std::any::type_name::<T>().to_owned(), value.to_string())
+}
+
+/// accepts BigInt or safe integer number
+impl JSValue<'_> for i64 {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        if is_bigint(env, js_value)? {
+            match env.get_value_bigint_int64(js_value)? {
+                (value, true) => Ok(value),
+                (_, false) => Err(bigint_out_of_range::<Self>(env, js_value)),
+            }
+        } else {
+            safe_integer(env, js_value)
+        }
+    }
+}
+
+/// accepts BigInt or non negative safe integer number
+impl JSValue<'_> for u64 {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        if is_bigint(env, js_value)? {
+            match env.get_value_bigint_uint64(js_value)? {
+                (value, true) => Ok(value),
+                (_, false) => Err(bigint_out_of_range::<Self>(env, js_value)),
+            }
+        } else {
+            let value = safe_integer(env, js_value)?;
+            Self::try_from(value).map_err(|_| out_of_range::<Self>(value))
+        }
+    }
+}
+
+impl JSValue<'_> for isize {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let value = i64::convert_to_rust(env, js_value)?;
+        Self::try_from(value).map_err(|_| out_of_range::<Self>(value))
+    }
+}
+
+impl JSValue<'_> for usize {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let value = u64::convert_to_rust(env, js_value)?;
+        Self::try_from(value).map_err(|_| out_of_range::<Self>(value))
+    }
+}
+
+macro_rules! impl_js_value_for_128 {
+    ($($ty:ty),+) => {
+        $(
+            /// accepts BigInt or safe integer number
+            impl JSValue<'_> for $ty {
+                fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+                    if is_bigint(env, js_value)? {
+                        let value = BigInt::convert_to_rust(env, js_value)?;
+                        Self::try_from(&value).map_err(|_| out_of_range::<Self>(value))
+                    } else {
+                        let value = safe_integer(env, js_value)?;
+                        Self::try_from(value).map_err(|_| out_of_range::<Self>(value))
+                    }
+                }
+            }
+        )+
+    }
+}
+
+impl_js_value_for_128!(i128, u128);
+
+/// converted to number, values beyond safe integer range are rejected.
+/// Use `BigInt::from` to return them as BigInt
+impl TryIntoJs for i64 {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        if self.unsigned_abs() <= MAX_SAFE_INTEGER as u64 {
+            js_env.create_int64(self)
+        } else {
+            Err(NjError::OutOfRange(
+                "safe integer".to_owned(),
+                self.to_string(),
+            ))
+        }
+    }
+}
+
+/// converted to number, values beyond safe integer range are rejected
+impl TryIntoJs for isize {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        (self as i64).try_to_js(js_env)
+    }
+}
+
+/// always converted to BigInt
+impl TryIntoJs for u64 {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_bigint_uint64(self)
+    }
+}
+
+/// always converted to BigInt
+impl TryIntoJs for usize {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_bigint_uint64(self as u64)
+    }
+}
+
+/// always converted to BigInt
+impl TryIntoJs for i128 {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let magnitude = self.unsigned_abs();
+        js_env.create_bigint_words(self < 0, &[magnitude as u64, (magnitude >> 64) as u64])
+    }
+}
+
+/// always converted to BigInt
+impl TryIntoJs for u128 {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_bigint_words(false, &[self as u64, (self >> 64) as u64])
+    }
+}
@@ -1,13 +1,15 @@
 use std::ptr;
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 use tracing::trace;
 
 use crate::TryIntoJs;
 use crate::JSValue;
-use crate::sys::{napi_value, napi_ref, napi_env};
-use crate::val::JsEnv;
+use crate::sys::{napi_value, napi_env};
+use crate::val::{JsEnv, JsObject, JsCallback, ExtractArgFromJs};
+use crate::reference::JsRef;
 use crate::NjError;
+use crate::typedarray::{typedarray_slice, typedarray_type_to_string};
 
 /// pass rust byte arry as Node.js ArrayBuffer
 pub struct ArrayBuffer {
@@ -69,19 +71,132 @@ impl TryIntoJs for ArrayBuffer {
     }
 }
 
+/// pass rust byte array as Node.js [Buffer](https://nodejs.org/api/buffer.html) without copying
+///
+/// On runtimes which don't allow external buffers, such as Electron with V8 sandbox,
+/// data is copied into new Buffer instead.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::buffer::NodeBuffer;
+///
+/// #[node_bindgen]
+/// fn payload(len: u32) -> NodeBuffer {
+///     NodeBuffer::new(vec![0; len as usize])
+/// }
+/// ```
+pub struct NodeBuffer {
+    data: Vec<u8>,
+}
+
+impl Debug for NodeBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("NodeBuffer len: {}", self.data.len()))
+    }
+}
+
+impl NodeBuffer {
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+
+    pub fn into_inner(self) -> Vec<u8> {
+        self.data
+    }
+
+    extern "C" fn finalize_buffer(
+        _env: napi_env,
+        _finalize_data: *mut ::std::os::raw::c_void,
+        finalize_hint: *mut ::std::os::raw::c_void,
+    ) {
+        trace!("finalize node buffer");
+        unsafe {
+            // use hint to reconstruct box instead of finalize data
+            let ptr: *mut Vec<u8> = finalize_hint as *mut Vec<u8>;
+            let _rust = Box::from_raw(ptr);
+        }
+    }
+}
+
+impl From<Vec<u8>> for NodeBuffer {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl Deref for NodeBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl TryIntoJs for NodeBuffer {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let len = self.data.len();
+
+        let box_data = Box::new(self.data);
+
+        let mut napi_buffer = ptr::null_mut();
+
+        // get pointer to vec's buffer
+        let data_buffer = box_data.as_ptr();
+
+        // get raw pointer to box, this will be used to reconstruct box
+        let data_box_ptr = Box::into_raw(box_data);
+
+        // status is checked manually since external buffers may not be allowed
+        let status = unsafe {
+            crate::sys::napi_create_external_buffer(
+                js_env.inner(),
+                len,
+                data_buffer as *mut core::ffi::c_void,
+                Some(Self::finalize_buffer),
+                data_box_ptr as *mut core::ffi::c_void,
+                &mut napi_buffer,
+            )
+        };
+
+        match status {
+            crate::sys::napi_status_napi_ok => Ok(napi_buffer),
+            crate::sys::napi_status_napi_no_external_buffers_allowed => {
+                trace!("external buffers not allowed, copying node buffer");
+                // finalizer is not registered, so we still own data
+                let data = unsafe { Box::from_raw(data_box_ptr) };
+                js_env.create_buffer_copy(&data)
+            }
+            _ => {
+                // finalizer is not registered, so we still own data
+                drop(unsafe { Box::from_raw(data_box_ptr) });
+                Err(NjError::NapiCall(status.into()))
+            }
+        }
+    }
+}
+
 impl<'a> JSValue<'a> for &'a [u8] {
     fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
         // check if this is really buffer
-        if !env.is_buffer(js_value)? {
-            return Err(NjError::InvalidType(
-                "Buffer".to_owned(),
-                env.value_type_string(js_value)?.to_owned(),
-            ));
+        if env.is_buffer(js_value)? {
+            return env.get_buffer_info(js_value);
         }
 
-        let buffer = env.get_buffer_info(js_value)?;
+        // other Uint8Array are accepted as well
+        if env.is_typedarray(js_value)? {
+            return typedarray_slice(env, js_value);
+        }
+
+        Err(NjError::InvalidType(
+            "Buffer".to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        ))
+    }
 
-        Ok(buffer)
+    fn viewed_bytes(&self) -> Option<(*const u8, usize)> {
+        Some((self.as_ptr(), self.len()))
     }
 }
 
@@ -110,8 +225,8 @@ impl<'a> JSValue<'a> for &'a [u8] {
 /// console.log(Buffer.from("hello"));
 /// ```
 pub struct JSArrayBuffer {
-    env: JsEnv,
-    napi_ref: napi_ref,
+    // keeps buffer alive while slice is in use
+    _reference: JsRef<JsObject>,
     buffer: &'static [u8],
 }
 
@@ -127,31 +242,233 @@ impl JSValue<'_> for JSArrayBuffer {
     fn convert_to_rust(env: &JsEnv, napi_value: napi_value) -> Result<Self, NjError> {
         use std::mem::transmute;
 
-        let napi_ref = env.create_reference(napi_value, 1)?;
-
         // it is oky to transmute as static byte slice since we are managing slice
         let buffer: &'static [u8] =
             unsafe { transmute::<&[u8], &'static [u8]>(env.convert_to_rust(napi_value)?) };
         Ok(Self {
-            env: *env,
-            napi_ref,
+            _reference: JsRef::new(env, napi_value)?,
             buffer,
         })
     }
+    fn viewed_bytes(&self) -> Option<(*const u8, usize)> {
+        Some((self.buffer.as_ptr(), self.buffer.len()))
+    }
 }
 
-impl Drop for JSArrayBuffer {
-    fn drop(&mut self) {
-        self.env
-            .delete_reference(self.napi_ref)
-            .expect("reference can't be deleted to array buf");
+impl Deref for JSArrayBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
     }
 }
 
-impl Deref for JSArrayBuffer {
+/// Rust representation of Nodejs [DataView](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView)
+/// Like JSArrayBuffer, this keeps DataView alive until dropped.
+/// JsDataView is deference as `&[u8]` covering only the window of the view.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::JsEnv;
+/// use node_bindgen::core::buffer::{ArrayBuffer, JsDataView};
+///
+/// #[node_bindgen]
+/// fn first_byte(view: JsDataView) -> Option<u32> {
+///     view.first().map(|byte| *byte as u32)
+/// }
+///
+/// #[node_bindgen]
+/// fn header(env: JsEnv) -> Result<JsDataView, NjError> {
+///     JsDataView::from_array_buffer(&env, ArrayBuffer::new(vec![0; 16]), 4, 8)
+/// }
+/// ```
+pub struct JsDataView {
+    reference: JsRef<JsObject>,
+    buffer: &'static [u8],
+    byte_offset: usize,
+}
+
+unsafe impl Send for JsDataView {}
+
+impl JsDataView {
+    /// create DataView over window of rust owned ArrayBuffer
+    pub fn from_array_buffer(
+        env: &JsEnv,
+        buffer: ArrayBuffer,
+        byte_offset: usize,
+        byte_length: usize,
+    ) -> Result<Self, NjError> {
+        let buffer_len = buffer.data.len();
+        if byte_offset
+            .checked_add(byte_length)
+            .is_none_or(|end| end > buffer_len)
+        {
+            return Err(NjError::Other(format!(
+                "DataView offset: {byte_offset} and length: {byte_length} out of buffer len: {buffer_len}"
+            )));
+        }
+
+        let napi_buffer = buffer.try_to_js(env)?;
+
+        let mut napi_data_view = ptr::null_mut();
+
+        crate::napi_call_result!(crate::sys::napi_create_dataview(
+            env.inner(),
+            byte_length,
+            napi_buffer,
+            byte_offset,
+            &mut napi_data_view
+        ))?;
+
+        Self::convert_to_rust(env, napi_data_view)
+    }
+
+    /// offset of the view into underlying ArrayBuffer
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer
+    }
+}
+
+impl JSValue<'_> for JsDataView {
+    fn convert_to_rust(env: &JsEnv, napi_value: napi_value) -> Result<Self, NjError> {
+        use std::mem::transmute;
+
+        if !env.is_dataview(napi_value)? {
+            return Err(NjError::InvalidType(
+                "DataView".to_owned(),
+                env.value_type_string(napi_value)?.to_owned(),
+            ));
+        }
+
+        let (window, byte_offset) = env.get_dataview_info(napi_value)?;
+
+        // it is oky to transmute as static byte slice since we are managing slice
+        let buffer: &'static [u8] = unsafe { transmute::<&[u8], &'static [u8]>(window) };
+        Ok(Self {
+            reference: JsRef::new(env, napi_value)?,
+            buffer,
+            byte_offset,
+        })
+    }
+    fn viewed_bytes(&self) -> Option<(*const u8, usize)> {
+        Some((self.buffer.as_ptr(), self.buffer.len()))
+    }
+}
+
+impl TryIntoJs for JsDataView {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        self.reference.napi_value(js_env)
+    }
+}
+
+impl Deref for JsDataView {
     type Target = [u8];
 
     fn deref(&self) -> &Self::Target {
         self.buffer
     }
 }
+
+/// get memory of Buffer, Uint8Array or ArrayBuffer which can be written to
+fn writable_bytes_info(env: &JsEnv, js_value: napi_value) -> Result<(*mut u8, usize), NjError> {
+    // Buffer is Uint8Array
+    if env.is_typedarray(js_value)? {
+        let (array_type, length, data) = env.get_typedarray_info(js_value)?;
+        if array_type != crate::sys::napi_typedarray_type_napi_uint8_array {
+            return Err(NjError::InvalidType(
+                "Buffer".to_owned(),
+                typedarray_type_to_string(array_type).to_owned(),
+            ));
+        }
+        return Ok((data as *mut u8, length));
+    }
+
+    if env.is_array_buffer(js_value)? {
+        let (data, byte_length) = env.get_arraybuffer_info(js_value)?;
+        return Ok((data as *mut u8, byte_length));
+    }
+
+    Err(NjError::InvalidType(
+        "Buffer".to_owned(),
+        env.value_type_string(js_value)?.to_owned(),
+    ))
+}
+
+/// write into memory of Buffer, Uint8Array or ArrayBuffer passed as argument.
+/// Same memory can't be passed as another mutable argument in the same call.
+impl<'a> ExtractArgFromJs<'a> for &'a mut [u8] {
+    fn convert_arg_at(js_cb: &'a JsCallback, index: usize) -> Result<Self, NjError> {
+        let (data, len) = writable_bytes_info(js_cb.env(), js_cb.arg_at(index)?)?;
+        js_cb.borrow_bytes_mut(data, len)
+    }
+}
+
+/// Mutable version of [JSArrayBuffer] for writing into Buffer, Uint8Array or ArrayBuffer
+/// passed from JS, such as filling caller provided buffer in place.
+///
+/// Same memory can't be passed as another argument in the same call.
+/// Memory is borrowed only for duration of the call, so it can't be kept after call returns.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::buffer::JSArrayBufferMut;
+///
+/// #[node_bindgen]
+/// fn fill(mut data: JSArrayBufferMut, value: u8) {
+///     data.fill(value);
+/// }
+/// ```
+pub struct JSArrayBufferMut<'a> {
+    napi_value: napi_value,
+    buffer: &'a mut [u8],
+}
+
+impl JSArrayBufferMut<'_> {
+    pub fn as_bytes(&self) -> &[u8] {
+        self.buffer
+    }
+
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        self.buffer
+    }
+}
+
+impl<'a> ExtractArgFromJs<'a> for JSArrayBufferMut<'a> {
+    fn convert_arg_at(js_cb: &'a JsCallback, index: usize) -> Result<Self, NjError> {
+        let js_value = js_cb.arg_at(index)?;
+        let (data, len) = writable_bytes_info(js_cb.env(), js_value)?;
+        Ok(Self {
+            napi_value: js_value,
+            buffer: js_cb.borrow_bytes_mut(data, len)?,
+        })
+    }
+}
+
+impl TryIntoJs for JSArrayBufferMut<'_> {
+    fn try_to_js(self, _js_env: &JsEnv) -> Result<napi_value, NjError> {
+        Ok(self.napi_value)
+    }
+}
+
+impl Deref for JSArrayBufferMut<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.buffer
+    }
+}
+
+impl DerefMut for JSArrayBufferMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.buffer
+    }
+}
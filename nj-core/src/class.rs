@@ -1,4 +1,7 @@
 use std::ptr;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::any::TypeId;
 
 use tracing::debug;
 use tracing::instrument;
@@ -7,9 +10,14 @@ use crate::sys::napi_value;
 use crate::sys::napi_env;
 use crate::sys::napi_callback_info;
 use crate::sys::napi_ref;
+use crate::sys::napi_type_tag;
 use crate::val::JsEnv;
+use crate::val::type_tag_of;
+use crate::val::type_id_of;
 use crate::val::JsExports;
 use crate::val::JsCallback;
+use crate::val::JsCallbackFunction;
+use crate::reference::JsRef;
 use crate::NjError;
 use crate::IntoJs;
 use crate::PropertiesBuilder;
@@ -37,6 +45,9 @@ where
     /// and saved the reference
     #[instrument(skip(self))]
     fn wrap(self, js_env: &JsEnv, js_cb: JsCallback) -> Result<napi_value, NjError> {
+        // tag instance so it can be checked before unwrapping
+        js_env.type_tag_object(js_cb.this(), &T::type_tag())?;
+
         let boxed_self = Box::new(self);
         let raw_ptr = Box::into_raw(boxed_self); // rust no longer manages this struct
         debug!(?raw_ptr, "box into raw");
@@ -52,37 +63,78 @@ where
     }
 }
 
+/// constructors of classes defined in env, each env loading the addon has its own
+#[derive(Default)]
+struct ClassConstructors {
+    constructors: RefCell<HashMap<TypeId, JsRef<JsCallbackFunction>>>,
+}
+
+fn class_constructors(js_env: &JsEnv) -> Result<&ClassConstructors, NjError> {
+    if let Some(constructors) = js_env.instance_data::<ClassConstructors>()? {
+        return Ok(constructors);
+    }
+
+    js_env.set_instance_data(ClassConstructors::default())?;
+    js_env
+        .instance_data::<ClassConstructors>()?
+        .ok_or_else(|| NjError::Other("class constructors are not set".to_owned()))
+}
+
 pub trait JSClass: Sized {
     const CLASS_NAME: &'static str;
 
+    /// tag attached to every instance, unique to this class
+    fn type_tag() -> napi_type_tag {
+        type_tag_of::<Self>()
+    }
+
     // create rust object from argument
     fn create_from_js(
         js_env: &JsEnv,
         cb: napi_callback_info,
     ) -> Result<(Self, JsCallback), NjError>;
 
-    fn set_constructor(constructor: napi_ref);
+    /// save constructor of this class defined in env
+    fn set_constructor(js_env: &JsEnv, constructor: napi_value) -> Result<(), NjError> {
+        let reference = JsRef::new(js_env, constructor)?;
+        class_constructors(js_env)?
+            .constructors
+            .borrow_mut()
+            .insert(type_id_of::<Self>(), reference);
+        Ok(())
+    }
 
-    fn get_constructor() -> napi_ref;
+    /// constructor of this class defined in env
+    fn get_constructor(js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let constructors = class_constructors(js_env)?.constructors.borrow();
+        constructors
+            .get(&type_id_of::<Self>())
+            .ok_or_else(|| {
+                NjError::Other(format!(
+                    "class {} is not defined in this env",
+                    Self::CLASS_NAME
+                ))
+            })?
+            .napi_value(js_env)
+    }
 
     /// new instance
     #[instrument]
     fn new_instance(js_env: &JsEnv, js_args: Vec<napi_value>) -> Result<napi_value, NjError> {
         debug!("new instance");
-        let constructor = js_env.get_reference_value(Self::get_constructor())?;
+        let constructor = Self::get_constructor(js_env)?;
         js_env.new_instance(constructor, js_args)
     }
 
     /// given instance, return my object
+    /// error if instance was not created by this class
     #[instrument]
     fn unwrap_mut(js_env: &JsEnv, instance: napi_value) -> Result<&'static mut Self, NjError> {
-        Ok(js_env
-            .unwrap_mut::<JSObjectWrapper<Self>>(instance)?
-            .mut_inner())
+        js_env.unwrap_mut::<Self>(instance)
     }
 
     fn unwrap(js_env: &JsEnv, instance: napi_value) -> Result<&'static Self, NjError> {
-        Ok(js_env.unwrap::<JSObjectWrapper<Self>>(instance)?.inner())
+        js_env.unwrap::<Self>(instance)
     }
 
     fn properties() -> PropertiesBuilder {
@@ -100,9 +152,8 @@ pub trait JSClass: Sized {
         debug!(?js_constructor, "class defined with constructor");
 
         // save the constructor reference, we need this later in order to instantiate
-        let js_ref = js_exports.env().create_reference(js_constructor, 1)?;
-        debug!(?js_constructor, "created reference to constructor");
-        Self::set_constructor(js_ref);
+        Self::set_constructor(js_exports.env(), js_constructor)?;
+        debug!(?js_constructor, "saved reference to constructor");
 
         js_exports.set_name_property(Self::CLASS_NAME, js_constructor)?;
         Ok(())
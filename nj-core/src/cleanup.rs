@@ -0,0 +1,243 @@
+use std::ptr;
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::os::raw::c_void;
+
+use tracing::debug;
+use tracing::error;
+
+use crate::sys::{napi_async_cleanup_hook_handle, napi_env, napi_value};
+use crate::val::JsEnv;
+use crate::NjError;
+use crate::ThreadSafeFunction;
+use crate::napi_call_result;
+
+type CleanupFn = Box<dyn FnOnce()>;
+
+/// handle to hook added by [`JsEnv::on_cleanup`].
+///
+/// Dropping handle keeps hook registered, use [`CleanupHook::remove`] to remove it.
+pub struct CleanupHook {
+    env: JsEnv,
+    func: Rc<RefCell<Option<CleanupFn>>>,
+    arg: *const RefCell<Option<CleanupFn>>,
+}
+
+impl CleanupHook {
+    pub(crate) fn add(env: &JsEnv, func: CleanupFn) -> Result<Self, NjError> {
+        let func = Rc::new(RefCell::new(Some(func)));
+        // hook owns one reference, released when it runs or is removed
+        let arg = Rc::into_raw(func.clone());
+
+        if let Err(err) = napi_call_result!(crate::sys::napi_add_env_cleanup_hook(
+            env.inner(),
+            Some(Self::run),
+            arg as *mut c_void
+        )) {
+            drop(unsafe { Rc::from_raw(arg) });
+            return Err(err);
+        }
+
+        Ok(Self {
+            env: *env,
+            func,
+            arg,
+        })
+    }
+
+    /// remove hook, closure is dropped without being called.
+    /// return false if hook has already run
+    pub fn remove(self) -> Result<bool, NjError> {
+        if self.func.borrow().is_none() {
+            return Ok(false);
+        }
+
+        napi_call_result!(crate::sys::napi_remove_env_cleanup_hook(
+            self.env.inner(),
+            Some(Self::run),
+            self.arg as *mut c_void
+        ))?;
+        drop(unsafe { Rc::from_raw(self.arg) });
+        self.func.borrow_mut().take();
+        Ok(true)
+    }
+
+    unsafe extern "C" fn run(arg: *mut c_void) {
+        debug!("env cleanup hook");
+        let func = Rc::from_raw(arg as *const RefCell<Option<CleanupFn>>);
+        let cleanup = func.borrow_mut().take();
+        if let Some(cleanup) = cleanup {
+            cleanup();
+        }
+    }
+}
+
+/// token passed to async cleanup closure, environment exits after [`AsyncCleanupDone::done`] is called.
+///
+/// Dropping token without calling `done`, such as on early return, signals cleanup is finished as well,
+/// so environment shutdown doesn't hang.
+/// Use [`AsyncCleanupDone::into_thread_safe`] to finish cleanup from other thread.
+pub struct AsyncCleanupDone {
+    env: JsEnv,
+    remove_handle: Option<napi_async_cleanup_hook_handle>,
+}
+
+impl AsyncCleanupDone {
+    /// signal cleanup is finished, must be called on main JS thread
+    pub fn done(mut self) -> Result<(), NjError> {
+        match self.remove_handle.take() {
+            Some(remove_handle) => {
+                napi_call_result!(crate::sys::napi_remove_async_cleanup_hook(remove_handle))
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// convert into token which can be sent to other thread, such as one finishing async work
+    pub fn into_thread_safe(mut self) -> Result<ThreadSafeCleanupDone, NjError> {
+        // cleanup hooks are called without handle scope
+        let _scope = self.env.open_handle_scope()?;
+        let function = self.env.create_thread_safe_function(
+            "async_cleanup_done",
+            None,
+            Some(ThreadSafeCleanupDone::finish),
+        )?;
+
+        Ok(ThreadSafeCleanupDone {
+            function,
+            remove_handle: self.remove_handle.take(),
+        })
+    }
+}
+
+impl Drop for AsyncCleanupDone {
+    fn drop(&mut self) {
+        if let Some(remove_handle) = self.remove_handle.take() {
+            if let Err(err) =
+                napi_call_result!(crate::sys::napi_remove_async_cleanup_hook(remove_handle))
+            {
+                error!("async cleanup hook can't be removed: {}", err);
+            }
+        }
+    }
+}
+
+/// token created by [`AsyncCleanupDone::into_thread_safe`], `done` can be called from any thread.
+///
+/// Hook is removed on main JS thread, environment exits after that.
+/// Dropping token without calling `done` signals cleanup is finished as well.
+pub struct ThreadSafeCleanupDone {
+    function: ThreadSafeFunction,
+    remove_handle: Option<napi_async_cleanup_hook_handle>,
+}
+
+unsafe impl Send for ThreadSafeCleanupDone {}
+
+impl ThreadSafeCleanupDone {
+    /// signal cleanup is finished
+    pub fn done(mut self) -> Result<(), NjError> {
+        self.send_done()
+    }
+
+    fn send_done(&mut self) -> Result<(), NjError> {
+        match self.remove_handle.take() {
+            Some(remove_handle) => self.function.call(Some(remove_handle as *mut c_void)),
+            None => Ok(()),
+        }
+    }
+
+    /// called on main JS thread with hook handle sent by `done`
+    unsafe extern "C" fn finish(
+        _env: napi_env,
+        _js_cb: napi_value,
+        _context: *mut c_void,
+        data: *mut c_void,
+    ) {
+        debug!("finish async cleanup from thread safe function");
+        let remove_handle = data as napi_async_cleanup_hook_handle;
+        if let Err(err) =
+            napi_call_result!(crate::sys::napi_remove_async_cleanup_hook(remove_handle))
+        {
+            error!("async cleanup hook can't be removed: {}", err);
+        }
+    }
+}
+
+impl Drop for ThreadSafeCleanupDone {
+    fn drop(&mut self) {
+        if let Err(err) = self.send_done() {
+            error!("async cleanup can't be finished: {}", err);
+        }
+    }
+}
+
+type AsyncCleanupFn = Box<dyn FnOnce(AsyncCleanupDone)>;
+
+struct AsyncCleanupState {
+    env: JsEnv,
+    func: Option<AsyncCleanupFn>,
+    remove_handle: napi_async_cleanup_hook_handle,
+}
+
+/// handle to hook added by [`JsEnv::on_async_cleanup`].
+///
+/// Dropping handle keeps hook registered, use [`AsyncCleanupHook::remove`] to remove it.
+pub struct AsyncCleanupHook {
+    state: Rc<RefCell<AsyncCleanupState>>,
+    arg: *const RefCell<AsyncCleanupState>,
+}
+
+impl AsyncCleanupHook {
+    pub(crate) fn add(env: &JsEnv, func: AsyncCleanupFn) -> Result<Self, NjError> {
+        let state = Rc::new(RefCell::new(AsyncCleanupState {
+            env: *env,
+            func: Some(func),
+            remove_handle: ptr::null_mut(),
+        }));
+        // hook owns one reference, released when it runs or is removed
+        let arg = Rc::into_raw(state.clone());
+
+        let mut remove_handle = ptr::null_mut();
+        if let Err(err) = napi_call_result!(crate::sys::napi_add_async_cleanup_hook(
+            env.inner(),
+            Some(Self::run),
+            arg as *mut c_void,
+            &mut remove_handle
+        )) {
+            drop(unsafe { Rc::from_raw(arg) });
+            return Err(err);
+        }
+        state.borrow_mut().remove_handle = remove_handle;
+
+        Ok(Self { state, arg })
+    }
+
+    /// remove hook, closure is dropped without being called.
+    /// return false if hook has already run
+    pub fn remove(self) -> Result<bool, NjError> {
+        if self.state.borrow().func.is_none() {
+            return Ok(false);
+        }
+
+        let remove_handle = self.state.borrow().remove_handle;
+        napi_call_result!(crate::sys::napi_remove_async_cleanup_hook(remove_handle))?;
+        drop(unsafe { Rc::from_raw(self.arg) });
+        self.state.borrow_mut().func.take();
+        Ok(true)
+    }
+
+    unsafe extern "C" fn run(remove_handle: napi_async_cleanup_hook_handle, arg: *mut c_void) {
+        debug!("env async cleanup hook");
+        let state = Rc::from_raw(arg as *const RefCell<AsyncCleanupState>);
+        let (env, cleanup) = {
+            let mut state = state.borrow_mut();
+            (state.env, state.func.take())
+        };
+        if let Some(cleanup) = cleanup {
+            cleanup(AsyncCleanupDone {
+                env,
+                remove_handle: Some(remove_handle),
+            });
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::Deref;
+
+use crate::JSValue;
+use crate::sys::napi_value;
+use crate::val::{JsEnv, JsObject};
+use crate::NjError;
+
+/// argument converted with JS coercion rules before conversion to rust type,
+/// so `Coerced<String>` accepts any value which can be converted to string,
+/// same as `String(value)` in JS.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::coerce::Coerced;
+///
+/// #[node_bindgen]
+/// fn greet(name: Coerced<String>) -> String {
+///     format!("hello {}", *name)
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coerced<T>(T);
+
+impl<T> Coerced<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Coerced<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+macro_rules! impl_js_value_for_coerced {
+    ($($ty:ty => $coerce:ident),+ $(,)?) => {
+        $(
+            impl JSValue<'_> for Coerced<$ty> {
+                fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+                    let coerced = env.$coerce(js_value)?;
+                    Ok(Self(<$ty>::convert_to_rust(env, coerced)?))
+                }
+            }
+        )+
+    }
+}
+
+impl_js_value_for_coerced! {
+    String => coerce_to_string,
+    f64 => coerce_to_number,
+    bool => coerce_to_bool,
+    JsObject => coerce_to_object,
+}
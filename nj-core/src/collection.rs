@@ -0,0 +1,432 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use std::hash::{BuildHasher, Hash};
+
+use crate::TryIntoJs;
+use crate::convert::ElementScopes;
+use crate::JSValue;
+use crate::sys::napi_value;
+use crate::val::{JsEnv, JsCallbackFunction};
+use crate::reference::JsRef;
+use crate::NjError;
+
+/// `Map` and `Set` builtins of env, captured when addon is loaded.
+/// Conversions call them directly, so user code replacing globals or prototype methods
+/// such as `Map.prototype.set` afterwards doesn't change results.
+struct Builtins {
+    map: JsRef<JsCallbackFunction>,
+    map_set: JsRef<JsCallbackFunction>,
+    map_entries: JsRef<JsCallbackFunction>,
+    map_iterator_next: JsRef<JsCallbackFunction>,
+    set: JsRef<JsCallbackFunction>,
+    set_add: JsRef<JsCallbackFunction>,
+    set_values: JsRef<JsCallbackFunction>,
+    set_iterator_next: JsRef<JsCallbackFunction>,
+}
+
+impl Builtins {
+    fn capture(env: &JsEnv) -> Result<Self, NjError> {
+        let global = env.get_global()?;
+
+        let map = named_property(env, global, "Map")?;
+        let map_prototype = named_property(env, map, "prototype")?;
+        let map_entries = named_property(env, map_prototype, "entries")?;
+        let map_iterator =
+            env.call_function(env.new_instance(map, vec![])?, map_entries, vec![])?;
+
+        let set = named_property(env, global, "Set")?;
+        let set_prototype = named_property(env, set, "prototype")?;
+        let set_values = named_property(env, set_prototype, "values")?;
+        let set_iterator = env.call_function(env.new_instance(set, vec![])?, set_values, vec![])?;
+
+        Ok(Self {
+            map: JsRef::new(env, map)?,
+            map_set: JsRef::new(env, named_property(env, map_prototype, "set")?)?,
+            map_entries: JsRef::new(env, map_entries)?,
+            map_iterator_next: JsRef::new(
+                env,
+                named_property(env, env.get_prototype(map_iterator)?, "next")?,
+            )?,
+            set: JsRef::new(env, set)?,
+            set_add: JsRef::new(env, named_property(env, set_prototype, "add")?)?,
+            set_values: JsRef::new(env, set_values)?,
+            set_iterator_next: JsRef::new(
+                env,
+                named_property(env, env.get_prototype(set_iterator)?, "next")?,
+            )?,
+        })
+    }
+}
+
+/// capture `Map` and `Set` builtins of env if not done yet
+pub(crate) fn init_builtins(env: &JsEnv) -> Result<(), NjError> {
+    if env.instance_data::<Builtins>()?.is_none() {
+        env.set_instance_data(Builtins::capture(env)?)?;
+    }
+    Ok(())
+}
+
+fn builtins(env: &JsEnv) -> Result<&Builtins, NjError> {
+    init_builtins(env)?;
+    env.instance_data::<Builtins>()?
+        .ok_or_else(|| NjError::Other("Map and Set builtins are not captured".to_owned()))
+}
+
+fn named_property(env: &JsEnv, object: napi_value, name: &str) -> Result<napi_value, NjError> {
+    env.get_property(object, env.create_string_utf8(name)?)
+}
+
+/// check value is instance of builtin class
+fn assert_instance(
+    env: &JsEnv,
+    js_value: napi_value,
+    class: &JsRef<JsCallbackFunction>,
+    class_name: &str,
+) -> Result<(), NjError> {
+    if env.value_type(js_value)? == crate::sys::napi_valuetype_napi_object
+        && env.instance_of(js_value, class.napi_value(env)?)?
+    {
+        Ok(())
+    } else {
+        Err(NjError::InvalidType(
+            class_name.to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        ))
+    }
+}
+
+/// check if value is JS `Map`
+pub(crate) fn is_map(env: &JsEnv, js_value: napi_value) -> Result<bool, NjError> {
+    Ok(
+        env.value_type(js_value)? == crate::sys::napi_valuetype_napi_object
+            && env.instance_of(js_value, builtins(env)?.map.napi_value(env)?)?,
+    )
+}
+
+/// collect values produced by builtin iterator such as `Map.prototype.entries`
+fn iterate(
+    env: &JsEnv,
+    iterable: napi_value,
+    iterator: &JsRef<JsCallbackFunction>,
+    next: &JsRef<JsCallbackFunction>,
+) -> Result<Vec<napi_value>, NjError> {
+    let iterator = env.call_function(iterable, iterator.napi_value(env)?, vec![])?;
+    let next = next.napi_value(env)?;
+
+    let mut values = vec![];
+    loop {
+        let result = env.call_function(iterator, next, vec![])?;
+        if bool::convert_to_rust(env, named_property(env, result, "done")?)? {
+            return Ok(values);
+        }
+        values.push(named_property(env, result, "value")?);
+    }
+}
+
+/// entries of JS [Map](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map)
+///
+/// Unlike plain objects, keys can be any JS value such as numbers or BigInt, and insertion order is preserved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::collections::HashMap;
+///
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::collection::JsMap;
+///
+/// #[node_bindgen]
+/// fn double(values: JsMap<u32, f64>) -> JsMap<u32, f64> {
+///     let values: HashMap<u32, f64> = values.into_iter().collect();
+///     values.into_iter().map(|(k, v)| (k, v * 2.0)).collect()
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> Default for JsMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> JsMap<K, V> {
+    pub fn new() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// append entry, keys are not checked for duplicates
+    pub fn push(&mut self, key: K, value: V) {
+        self.entries.push((key, value));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// entries in insertion order
+    pub fn entries(&self) -> &[(K, V)] {
+        &self.entries
+    }
+
+    pub fn into_entries(self) -> Vec<(K, V)> {
+        self.entries
+    }
+}
+
+impl<K, V> FromIterator<(K, V)> for JsMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        Self {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<K, V> IntoIterator for JsMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<K, V, S> From<HashMap<K, V, S>> for JsMap<K, V> {
+    fn from(map: HashMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V> From<BTreeMap<K, V>> for JsMap<K, V> {
+    fn from(map: BTreeMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "convert-indexmap")]
+impl<K, V, S> From<indexmap::IndexMap<K, V, S>> for JsMap<K, V> {
+    fn from(map: indexmap::IndexMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<K, V> TryIntoJs for JsMap<K, V>
+where
+    K: TryIntoJs,
+    V: TryIntoJs,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let builtins = builtins(js_env)?;
+        let map = js_env.new_instance(builtins.map.napi_value(js_env)?, vec![])?;
+        let set = builtins.map_set.napi_value(js_env)?;
+        let mut scopes = ElementScopes::new(js_env);
+        for (key, value) in self.entries {
+            scopes.convert(|| {
+                let js_key = key.try_to_js(js_env)?;
+                let js_value = value.try_to_js(js_env)?;
+                js_env.call_function(map, set, vec![js_key, js_value])?;
+                Ok(())
+            })?;
+        }
+
+        Ok(map)
+    }
+}
+
+impl<'a, K, V> JSValue<'a> for JsMap<K, V>
+where
+    K: JSValue<'a>,
+    V: JSValue<'a>,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let builtins = builtins(env)?;
+        assert_instance(env, js_value, &builtins.map, "Map")?;
+
+        iterate(
+            env,
+            js_value,
+            &builtins.map_entries,
+            &builtins.map_iterator_next,
+        )?
+        .into_iter()
+        .map(|entry| {
+            let key = K::convert_to_rust(env, env.get_element(entry, 0)?)?;
+            let value = V::convert_to_rust(env, env.get_element(entry, 1)?)?;
+            Ok((key, value))
+        })
+        .collect()
+    }
+}
+
+/// values of JS [Set](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set)
+///
+/// Insertion order is preserved.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsSet<T> {
+    values: Vec<T>,
+}
+
+impl<T> Default for JsSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> JsSet<T> {
+    pub fn new() -> Self {
+        Self { values: vec![] }
+    }
+
+    /// append value, values are not checked for duplicates
+    pub fn push(&mut self, value: T) {
+        self.values.push(value);
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// values in insertion order
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+}
+
+impl<T> FromIterator<T> for JsSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> IntoIterator for JsSet<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<T, S> From<HashSet<T, S>> for JsSet<T> {
+    fn from(set: HashSet<T, S>) -> Self {
+        set.into_iter().collect()
+    }
+}
+
+impl<T> From<BTreeSet<T>> for JsSet<T> {
+    fn from(set: BTreeSet<T>) -> Self {
+        set.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "convert-indexmap")]
+impl<T, S> From<indexmap::IndexSet<T, S>> for JsSet<T> {
+    fn from(set: indexmap::IndexSet<T, S>) -> Self {
+        set.into_iter().collect()
+    }
+}
+
+impl<T> TryIntoJs for JsSet<T>
+where
+    T: TryIntoJs,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let builtins = builtins(js_env)?;
+        let set = js_env.new_instance(builtins.set.napi_value(js_env)?, vec![])?;
+        let add = builtins.set_add.napi_value(js_env)?;
+        let mut scopes = ElementScopes::new(js_env);
+        for value in self.values {
+            scopes.convert(|| {
+                let js_value = value.try_to_js(js_env)?;
+                js_env.call_function(set, add, vec![js_value])?;
+                Ok(())
+            })?;
+        }
+
+        Ok(set)
+    }
+}
+
+impl<'a, T> JSValue<'a> for JsSet<T>
+where
+    T: JSValue<'a>,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let builtins = builtins(env)?;
+        assert_instance(env, js_value, &builtins.set, "Set")?;
+
+        iterate(
+            env,
+            js_value,
+            &builtins.set_values,
+            &builtins.set_iterator_next,
+        )?
+        .into_iter()
+        .map(|value| T::convert_to_rust(env, value))
+        .collect()
+    }
+}
+
+impl<'a, T, S> JSValue<'a> for HashSet<T, S>
+where
+    T: JSValue<'a> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Ok(JsSet::<T>::convert_to_rust(env, js_value)?
+            .into_iter()
+            .collect())
+    }
+}
+
+impl<'a, T> JSValue<'a> for BTreeSet<T>
+where
+    T: JSValue<'a> + Ord,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Ok(JsSet::<T>::convert_to_rust(env, js_value)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(feature = "convert-indexmap")]
+impl<'a, T, S> JSValue<'a> for indexmap::IndexSet<T, S>
+where
+    T: JSValue<'a> + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Ok(JsSet::<T>::convert_to_rust(env, js_value)?
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(feature = "convert-indexmap")]
+impl<'a, K, V, S> JSValue<'a> for indexmap::IndexMap<K, V, S>
+where
+    K: JSValue<'a> + Eq + Hash,
+    V: JSValue<'a>,
+    S: BuildHasher + Default,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        crate::convert::map_or_object_entries(env, js_value)
+    }
+}
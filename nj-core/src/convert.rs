@@ -1,9 +1,14 @@
 use std::ptr;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use tracing::debug;
 
 use crate::sys::napi_value;
 use crate::val::JsEnv;
+use crate::val::EscapableHandleScope;
+use crate::collection::JsMap;
 use crate::NjError;
 use crate::napi_call_result;
 
@@ -24,6 +29,12 @@ impl TryIntoJs for f64 {
     }
 }
 
+impl TryIntoJs for f32 {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_double(self as f64)
+    }
+}
+
 impl TryIntoJs for i8 {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
         js_env.create_int32(self as i32)
@@ -42,12 +53,6 @@ impl TryIntoJs for i32 {
     }
 }
 
-impl TryIntoJs for i64 {
-    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
-        js_env.create_int64(self)
-    }
-}
-
 impl TryIntoJs for u8 {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
         js_env.create_uint32(self as u32)
@@ -66,15 +71,9 @@ impl TryIntoJs for u32 {
     }
 }
 
-impl TryIntoJs for u64 {
+impl TryIntoJs for char {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
-        js_env.create_bigint_uint64(self)
-    }
-}
-
-impl TryIntoJs for usize {
-    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
-        js_env.create_bigint_uint64(self as u64)
+        js_env.create_string_utf8(self.encode_utf8(&mut [0; 4]))
     }
 }
 
@@ -148,6 +147,77 @@ impl JSValue<'_> for uuid::Uuid {
     }
 }
 
+impl TryIntoJs for SystemTime {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let millis = match self.duration_since(UNIX_EPOCH) {
+            Ok(since_epoch) => since_epoch.as_millis() as i128,
+            Err(err) => -(err.duration().as_millis() as i128),
+        };
+
+        create_date_in_range(js_env, millis)
+    }
+}
+
+impl JSValue<'_> for SystemTime {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let millis = date_millis(env, js_value)?;
+        let since_epoch = Duration::from_millis(millis.unsigned_abs());
+
+        let time = if millis >= 0 {
+            UNIX_EPOCH.checked_add(since_epoch)
+        } else {
+            UNIX_EPOCH.checked_sub(since_epoch)
+        };
+
+        time.ok_or_else(|| NjError::Other(format!("Date out of range: {millis}")))
+    }
+}
+
+/// largest distance from unix epoch in milliseconds which js date can represent
+const MAX_DATE_MILLIS: i128 = 8_640_000_000_000_000;
+
+/// create js date, times which can't be represented are rejected instead of becoming Invalid Date
+fn create_date_in_range(env: &JsEnv, millis: i128) -> Result<napi_value, NjError> {
+    if millis.abs() > MAX_DATE_MILLIS {
+        return Err(NjError::OutOfRange("Date".to_owned(), millis.to_string()));
+    }
+
+    env.create_date(millis as f64)
+}
+
+/// milliseconds since unix epoch of valid js date
+fn date_millis(env: &JsEnv, js_value: napi_value) -> Result<i64, NjError> {
+    if !env.is_date(js_value)? {
+        return Err(NjError::InvalidType(
+            "Date".to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        ));
+    }
+
+    let millis = env.get_date_value(js_value)?;
+    if millis.is_nan() {
+        return Err(NjError::Other("Invalid Date".to_owned()));
+    }
+
+    Ok(millis as i64)
+}
+
+#[cfg(feature = "convert-chrono")]
+impl TryIntoJs for chrono::DateTime<chrono::Utc> {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        create_date_in_range(js_env, self.timestamp_millis() as i128)
+    }
+}
+
+#[cfg(feature = "convert-chrono")]
+impl JSValue<'_> for chrono::DateTime<chrono::Utc> {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let millis = date_millis(env, js_value)?;
+        chrono::DateTime::from_timestamp_millis(millis)
+            .ok_or_else(|| NjError::Other(format!("Date out of range: {millis}")))
+    }
+}
+
 impl<T, E> TryIntoJs for Result<T, E>
 where
     T: TryIntoJs,
@@ -179,21 +249,109 @@ impl TryIntoJs for napi_value {
     }
 }
 
+/// number of collection elements converted in one handle scope
+const ELEMENTS_PER_SCOPE: usize = 256;
+
+/// handle scopes releasing temporary values created while converting elements of collection.
+/// New scope is opened for each chunk of elements, so converted values must be stored in collection
+/// before next element is converted
+pub(crate) struct ElementScopes<'a> {
+    js_env: &'a JsEnv,
+    scope: Option<EscapableHandleScope>,
+    // elements which can still be converted in current scope
+    remaining: usize,
+}
+
+impl<'a> ElementScopes<'a> {
+    pub(crate) fn new(js_env: &'a JsEnv) -> Self {
+        Self {
+            js_env,
+            scope: None,
+            remaining: 0,
+        }
+    }
+
+    /// convert and store single element in current scope
+    pub(crate) fn convert<F>(&mut self, convert: F) -> Result<(), NjError>
+    where
+        F: FnOnce() -> Result<(), NjError>,
+    {
+        if self.remaining == 0 {
+            // previous scope must be closed before next one is opened
+            self.scope = None;
+            self.scope = Some(self.js_env.open_escapable_handle_scope()?);
+            self.remaining = ELEMENTS_PER_SCOPE;
+        }
+        self.remaining -= 1;
+
+        // js error is created in this scope, escape it so it stays valid when scope is closed
+        convert().map_err(|err| match (err, &self.scope) {
+            (NjError::Native(value), Some(scope)) => match scope.escape(value) {
+                Ok(value) => NjError::Native(value),
+                Err(err) => err,
+            },
+            (err, _) => err,
+        })
+    }
+}
+
 impl<T> TryIntoJs for Vec<T>
 where
     T: TryIntoJs,
 {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
         let array = js_env.create_array_with_len(self.len())?;
+        let mut scopes = ElementScopes::new(js_env);
         for (i, element) in self.into_iter().enumerate() {
-            let js_element = element.try_to_js(js_env)?;
-            js_env.set_element(array, js_element, i)?;
+            // element is kept alive by array
+            scopes.convert(|| {
+                let js_element = element.try_to_js(js_env)?;
+                js_env.set_element(array, js_element, i)
+            })?;
         }
 
         Ok(array)
     }
 }
 
+/// set entries as properties of new plain js object
+fn entries_to_js<T>(
+    js_env: &JsEnv,
+    entries: impl IntoIterator<Item = (String, T)>,
+) -> Result<napi_value, NjError>
+where
+    T: TryIntoJs,
+{
+    let object = js_env.create_object()?;
+    let mut scopes = ElementScopes::new(js_env);
+    for (key, value) in entries {
+        scopes.convert(|| {
+            let js_value = value.try_to_js(js_env)?;
+            js_env.set_property(object, js_env.create_string_utf8(&key)?, js_value)
+        })?;
+    }
+
+    Ok(object)
+}
+
+impl<T, S> TryIntoJs for HashMap<String, T, S>
+where
+    T: TryIntoJs,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        entries_to_js(js_env, self)
+    }
+}
+
+impl<T> TryIntoJs for BTreeMap<String, T>
+where
+    T: TryIntoJs,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        entries_to_js(js_env, self)
+    }
+}
+
 #[cfg(feature = "serde_json")]
 impl TryIntoJs for serde_json::map::Map<String, serde_json::Value> {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
@@ -226,6 +384,13 @@ pub trait JSValue<'a>: Sized {
     }
 
     fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError>;
+
+    /// memory of ArrayBuffer viewed by value as pointer and byte length, such as for `&[u8]`.
+    /// it is recorded when value is passed as argument, so same memory can't be borrowed mutably
+    /// by another argument of the call
+    fn viewed_bytes(&self) -> Option<(*const u8, usize)> {
+        None
+    }
 }
 
 impl JSValue<'_> for f64 {
@@ -246,54 +411,66 @@ impl JSValue<'_> for f64 {
     }
 }
 
-impl JSValue<'_> for i32 {
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
-        env.assert_type(js_value, crate::sys::napi_valuetype_napi_number)?;
+/// read js number which must be integer within range of `T`
+fn integer_in_range<T>(env: &JsEnv, js_value: napi_value) -> Result<T, NjError>
+where
+    T: TryFrom<i64>,
+{
+    let value = f64::convert_to_rust(env, js_value)?;
 
-        let mut value: i32 = 0;
+    // fract of NaN and infinity is NaN, large values saturate and fail range check
+    if value.fract() != 0.0 {
+        return Err(NjError::InvalidType(
+            std::any::type_name::<T>().to_owned(),
+            format!("number {value}"),
+        ));
+    }
 
-        napi_call_result!(crate::sys::napi_get_value_int32(
-            env.inner(),
-            js_value,
-            &mut value
-        ))?;
+    T::try_from(value as i64)
+        .map_err(|_| NjError::OutOfRange(std::any::type_name::<T>().to_owned(), value.to_string()))
+}
 
-        Ok(value)
+macro_rules! impl_js_value_for_integer {
+    ($($ty:ty),+) => {
+        $(
+            /// accepts only integral numbers within range
+            impl JSValue<'_> for $ty {
+                fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+                    integer_in_range(env, js_value)
+                }
+            }
+        )+
     }
 }
 
-impl JSValue<'_> for u32 {
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
-    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
-        env.assert_type(js_value, crate::sys::napi_valuetype_napi_number)?;
+impl_js_value_for_integer!(i8, i16, i32, u8, u16, u32);
 
-        let mut value: u32 = 0;
+/// accepts numbers within range of f32, precision may be lost
+impl JSValue<'_> for f32 {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        let value = f64::convert_to_rust(env, js_value)?;
 
-        napi_call_result!(crate::sys::napi_get_value_uint32(
-            env.inner(),
-            js_value,
-            &mut value
-        ))?;
+        if value.is_finite() && value.abs() > f32::MAX as f64 {
+            return Err(NjError::OutOfRange("f32".to_owned(), value.to_string()));
+        }
 
-        Ok(value)
+        Ok(value as f32)
     }
 }
 
-impl JSValue<'_> for i64 {
-    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+/// accepts string with single unicode character
+impl JSValue<'_> for char {
     fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
-        env.assert_type(js_value, crate::sys::napi_valuetype_napi_number)?;
-
-        let mut value: i64 = 0;
-
-        napi_call_result!(crate::sys::napi_get_value_int64(
-            env.inner(),
-            js_value,
-            &mut value
-        ))?;
-
-        Ok(value)
+        let value = String::convert_to_rust(env, js_value)?;
+
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Ok(c),
+            _ => Err(NjError::InvalidType(
+                "char".to_owned(),
+                format!("string of length {}", value.chars().count()),
+            )),
+        }
     }
 }
 
@@ -403,6 +580,73 @@ where
     }
 }
 
+/// convert enumerable properties of js object into entries, keys are converted from strings
+fn js_to_entries<'a, K, V, C>(env: &'a JsEnv, js_value: napi_value) -> Result<C, NjError>
+where
+    K: JSValue<'a>,
+    V: JSValue<'a>,
+    C: FromIterator<(K, V)>,
+{
+    if env.value_type(js_value)? != crate::sys::napi_valuetype_napi_object {
+        return Err(NjError::InvalidType(
+            "object".to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        ));
+    }
+
+    let keys = env.get_property_names(js_value)?;
+    let length = env.get_array_length(keys)?;
+
+    (0..length)
+        .map(|i| {
+            let js_key = env.get_element(keys, i)?;
+            let key = K::convert_to_rust(env, js_key)?;
+            let value = V::convert_to_rust(env, env.get_property(js_value, js_key)?)?;
+            Ok((key, value))
+        })
+        .collect()
+}
+
+/// convert entries of JS `Map`, or enumerable properties of plain object
+pub(crate) fn map_or_object_entries<'a, K, V, C>(
+    env: &'a JsEnv,
+    js_value: napi_value,
+) -> Result<C, NjError>
+where
+    K: JSValue<'a>,
+    V: JSValue<'a>,
+    C: FromIterator<(K, V)>,
+{
+    if crate::collection::is_map(env, js_value)? {
+        Ok(JsMap::<K, V>::convert_to_rust(env, js_value)?
+            .into_iter()
+            .collect())
+    } else {
+        js_to_entries(env, js_value)
+    }
+}
+
+impl<'a, K, V, S> JSValue<'a> for HashMap<K, V, S>
+where
+    K: JSValue<'a> + Eq + Hash,
+    V: JSValue<'a>,
+    S: BuildHasher + Default,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        map_or_object_entries(env, js_value)
+    }
+}
+
+impl<'a, K, V> JSValue<'a> for BTreeMap<K, V>
+where
+    K: JSValue<'a> + Ord,
+    V: JSValue<'a>,
+{
+    fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        map_or_object_entries(env, js_value)
+    }
+}
+
 macro_rules! impl_js_value_for_tuple {
     ( $( $len:expr => ( $( $n:tt $t:ident ),+ $(,)? ))+ ) => {
         $(
@@ -14,6 +14,8 @@ pub enum NjError {
     InvalidArgCount(usize, usize),
     InvalidArgIndex(usize, usize),
     InvalidType(String, String),
+    /// value can't be represented by rust type: (type, value)
+    OutOfRange(String, String),
     NoPlainConstructor,
     Utf8Error(FromUtf8Error),
     Utf8ErrorSlice(Utf8Error),
@@ -29,6 +31,10 @@ impl IntoJs for NjError {
                 js_env.throw(err);
                 ptr::null_mut()
             }
+            NjError::OutOfRange(..) => {
+                js_env.throw_range_error(&self.to_string());
+                ptr::null_mut()
+            }
             _ => {
                 let msg = self.to_string();
                 js_env.throw_type_error(&msg);
@@ -43,6 +49,9 @@ impl NjError {
     pub fn as_js(&self, js_env: &JsEnv) -> napi_value {
         match self {
             NjError::Native(err) => *err,
+            NjError::OutOfRange(..) => js_env
+                .create_range_error(&self.to_string())
+                .expect("error cannot be created"),
             _ => {
                 let msg = self.to_string();
                 js_env.create_error(&msg).expect("error cannot be created")
@@ -85,6 +94,7 @@ impl fmt::Display for NjError {
             Self::InvalidType(expected, actual) => {
                 write!(f, "invalid type, expected: {expected}, actual: {actual}")
             }
+            Self::OutOfRange(ty, value) => write!(f, "value {value} out of range for {ty}"),
             Self::Utf8Error(err) => write!(f, "ut8 error: {err}"),
             Self::Utf8ErrorSlice(err) => write!(f, "ut8 error: {err}"),
             Self::InvalidArgIndex(index, len) => {
@@ -101,6 +111,8 @@ impl fmt::Display for NjError {
     }
 }
 
+impl std::error::Error for NjError {}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum NapiStatus {
     Ok = crate::sys::napi_status_napi_ok as isize,
@@ -125,6 +137,9 @@ pub enum NapiStatus {
     ArraybufferExpected = crate::sys::napi_status_napi_arraybuffer_expected as isize,
     DetachableArrayBufferExpected =
         crate::sys::napi_status_napi_detachable_arraybuffer_expected as isize,
+    WouldDeadlock = crate::sys::napi_status_napi_would_deadlock as isize,
+    NoExternalBuffersAllowed = crate::sys::napi_status_napi_no_external_buffers_allowed as isize,
+    CannotRunJs = crate::sys::napi_status_napi_cannot_run_js as isize,
 }
 
 impl From<napi_status> for NapiStatus {
@@ -153,6 +168,11 @@ impl From<napi_status> for NapiStatus {
             crate::sys::napi_status_napi_detachable_arraybuffer_expected => {
                 Self::DetachableArrayBufferExpected
             }
+            crate::sys::napi_status_napi_would_deadlock => Self::WouldDeadlock,
+            crate::sys::napi_status_napi_no_external_buffers_allowed => {
+                Self::NoExternalBuffersAllowed
+            }
+            crate::sys::napi_status_napi_cannot_run_js => Self::CannotRunJs,
             _ => panic!("cannot convert: {}", status),
         }
     }
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use tracing::trace;
+
+use crate::TryIntoJs;
+use crate::JSValue;
+use crate::sys::{napi_value, napi_env};
+use crate::val::{JsEnv, type_tag_of};
+use crate::reference::JsRef;
+use crate::NjError;
+
+/// Opaque handle passing rust value to JS as [external](https://nodejs.org/api/n-api.html#napi_create_external) value.
+/// Value is dropped when JS handle is garbage collected.
+///
+/// When passed back from JS, type of value is checked and `JsExternal` derefs to value.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::external::JsExternal;
+///
+/// struct Cursor {
+///     position: u32,
+/// }
+///
+/// #[node_bindgen]
+/// fn open_cursor(position: u32) -> JsExternal<Cursor> {
+///     JsExternal::new(Cursor { position })
+/// }
+///
+/// #[node_bindgen]
+/// fn cursor_position(cursor: JsExternal<Cursor>) -> u32 {
+///     cursor.position
+/// }
+/// ```
+pub struct JsExternal<T: 'static> {
+    inner: ExternalInner<T>,
+}
+
+enum ExternalInner<T: 'static> {
+    // not yet passed to JS
+    Owned(Box<T>),
+    // owned by JS, kept alive by reference
+    Js {
+        reference: JsRef<napi_value>,
+        value: &'static T,
+    },
+}
+
+unsafe impl<T: Send> Send for JsExternal<T> {}
+
+impl<T> Debug for JsExternal<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!("JsExternal<{}>", std::any::type_name::<T>()))
+    }
+}
+
+impl<T: 'static> JsExternal<T> {
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: ExternalInner::Owned(Box::new(value)),
+        }
+    }
+
+    extern "C" fn finalize_external(
+        _env: napi_env,
+        finalize_data: *mut ::std::os::raw::c_void,
+        _finalize_hint: *mut ::std::os::raw::c_void,
+    ) {
+        trace!("finalize external {}", std::any::type_name::<T>());
+        unsafe {
+            let _rust = Box::from_raw(finalize_data as *mut T);
+        }
+    }
+}
+
+impl<T: 'static> Deref for JsExternal<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        match &self.inner {
+            ExternalInner::Owned(value) => value,
+            ExternalInner::Js { value, .. } => value,
+        }
+    }
+}
+
+impl<T: 'static> TryIntoJs for JsExternal<T> {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        match self.inner {
+            ExternalInner::Owned(value) => {
+                // finalizer takes ownership of box
+                let external = js_env
+                    .create_external(Box::into_raw(value) as *mut u8, Self::finalize_external)?;
+                js_env.type_tag_object(external, &type_tag_of::<T>())?;
+                Ok(external)
+            }
+            ExternalInner::Js { reference, .. } => reference.napi_value(js_env),
+        }
+    }
+}
+
+impl<T: 'static> JSValue<'_> for JsExternal<T> {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        env.assert_type(js_value, crate::sys::napi_valuetype_napi_external)?;
+
+        if !env.check_object_type_tag(js_value, &type_tag_of::<T>())? {
+            return Err(NjError::InvalidType(
+                format!("JsExternal<{}>", std::any::type_name::<T>()),
+                "external of other type".to_owned(),
+            ));
+        }
+
+        let data = env.get_value_external(js_value)?;
+        let reference = JsRef::with_count(env, js_value, 1)?;
+
+        // value is kept alive by reference until this is dropped
+        let value: &'static T = unsafe { &*(data as *const T) };
+        Ok(Self {
+            inner: ExternalInner::Js { reference, value },
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use tracing::debug;
+
+use crate::TryIntoJs;
+use crate::IntoJs;
+use crate::sys::{napi_value, napi_env, napi_callback_info};
+use crate::val::{JsEnv, JsCallback, ExtractArgFromJs};
+use crate::NjError;
+
+/// arguments of [`JsFn`] closure as tuple, implemented for up to 8 arguments.
+///
+/// Each argument is converted with `ExtractArgFromJs`, same as arguments of `#[node_bindgen]` functions.
+pub trait JsFnArgs: Sized {
+    /// number of arguments
+    const COUNT: usize;
+
+    fn extract(js_cb: &JsCallback) -> Result<Self, NjError>;
+}
+
+macro_rules! impl_js_fn_args {
+    ($count:expr; $($ty:ident $index:tt),*) => {
+        impl<$($ty),*> JsFnArgs for ($($ty,)*)
+        where
+            $($ty: for<'a> ExtractArgFromJs<'a>,)*
+        {
+            const COUNT: usize = $count;
+
+            #[allow(unused_variables)]
+            fn extract(js_cb: &JsCallback) -> Result<Self, NjError> {
+                Ok(($($ty::convert_arg_at(js_cb, $index)?,)*))
+            }
+        }
+    };
+}
+
+impl_js_fn_args!(0;);
+impl_js_fn_args!(1; A 0);
+impl_js_fn_args!(2; A 0, B 1);
+impl_js_fn_args!(3; A 0, B 1, C 2);
+impl_js_fn_args!(4; A 0, B 1, C 2, D 3);
+impl_js_fn_args!(5; A 0, B 1, C 2, D 3, E 4);
+impl_js_fn_args!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_js_fn_args!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_js_fn_args!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+type BoxedFn<Args, R> = Box<dyn Fn(Args) -> R>;
+
+/// Rust closure returned to JS as function.
+///
+/// Arguments are passed as tuple, `JsEnv` can be used as last element to get current env.
+/// Closure is dropped when JS function is garbage collected.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::function::JsFn;
+///
+/// #[node_bindgen]
+/// fn adder(base: f64) -> JsFn<(f64,), f64> {
+///     JsFn::new(move |(value,)| base + value)
+/// }
+/// ```
+pub struct JsFn<Args, R> {
+    name: String,
+    func: BoxedFn<Args, R>,
+}
+
+impl<Args, R> JsFn<Args, R>
+where
+    Args: JsFnArgs + 'static,
+    R: TryIntoJs + 'static,
+{
+    pub fn new<F>(func: F) -> Self
+    where
+        F: Fn(Args) -> R + 'static,
+    {
+        Self {
+            name: String::new(),
+            func: Box::new(func),
+        }
+    }
+
+    /// set `name` of JS function
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_owned();
+        self
+    }
+
+    extern "C" fn js_call(env: napi_env, info: napi_callback_info) -> napi_value {
+        let js_env = JsEnv::new(env);
+
+        let result: Result<napi_value, NjError> = (|| {
+            let func = js_env.get_cb_data(info)? as *const BoxedFn<Args, R>;
+            let js_cb = js_env.get_cb_info(info, Args::COUNT)?;
+            let args = Args::extract(&js_cb)?;
+            // data is alive until function is finalized
+            let func = unsafe { &*func };
+            func(args).try_to_js(&js_env)
+        })();
+
+        result.into_js(&js_env)
+    }
+
+    extern "C" fn js_finalize(
+        _env: napi_env,
+        finalize_data: *mut ::std::os::raw::c_void,
+        _finalize_hint: *mut ::std::os::raw::c_void,
+    ) {
+        debug!("js fn finalize");
+        unsafe {
+            drop(Box::from_raw(finalize_data as *mut BoxedFn<Args, R>));
+        }
+    }
+}
+
+impl<Args, R> TryIntoJs for JsFn<Args, R>
+where
+    Args: JsFnArgs + 'static,
+    R: TryIntoJs + 'static,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let data = Box::into_raw(Box::new(self.func));
+
+        let function = match js_env.create_function(&self.name, Self::js_call, data as *mut _) {
+            Ok(function) => function,
+            Err(err) => {
+                drop(unsafe { Box::from_raw(data) });
+                return Err(err);
+            }
+        };
+
+        // function is not handed out on error, so closure can't be called anymore
+        if let Err(err) = js_env.add_finalizer(function, data as *mut _, Self::js_finalize) {
+            drop(unsafe { Box::from_raw(data) });
+            return Err(err);
+        }
+        Ok(function)
+    }
+}
@@ -10,6 +10,17 @@ pub mod buffer;
 pub mod bigint;
 pub mod stream;
 pub mod safebuffer;
+pub mod typedarray;
+pub mod collection;
+pub mod symbol;
+pub mod string;
+pub mod coerce;
+pub mod external;
+pub mod function;
+pub mod reference;
+pub mod cleanup;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 pub use thread_fn::ThreadSafeFunction;
 pub use error::*;
@@ -1,9 +1,10 @@
-use std::ptr;
+use std::sync::Once;
 
 use inventory::Collect;
 use inventory::submit;
 use inventory::iter;
 use inventory::Registry;
+use tracing::error;
 
 use crate::Property;
 use crate::val::JsExports;
@@ -34,11 +35,18 @@ pub fn submit_register_callback(callback: ClassCallback) {
     submit::<NapiRegister>(NapiRegister::Callback(callback));
 }
 
-#[no_mangle]
+/// define registered properties and classes on exports of env loading the addon
 pub extern "C" fn init_modules(env: napi_env, exports: napi_value) -> napi_value {
-    fluvio_future::subscriber::init_tracer(None);
+    static TRACER: Once = Once::new();
+    TRACER.call_once(|| fluvio_future::subscriber::init_tracer(None));
 
     let mut js_exports = JsExports::new(env, exports);
+
+    // capture builtins before user code loaded after addon can replace them
+    if let Err(err) = crate::collection::init_builtins(js_exports.env()) {
+        error!(%err, "capturing Map and Set builtins failed");
+    }
+
     let mut prop_builder = js_exports.prop_builder();
 
     for register in iter::<NapiRegister> {
@@ -61,29 +69,14 @@ pub extern "C" fn init_modules(env: napi_env, exports: napi_value) -> napi_value
     exports
 }
 
-#[crate::ctor]
-fn init_module() {
-    use crate::c_str;
-    use crate::sys::NAPI_VERSION;
-    use crate::sys::napi_module;
-    use crate::sys::napi_module_register;
-
-    static mut _MODULE: napi_module = napi_module {
-        nm_version: NAPI_VERSION as i32,
-        nm_flags: 0,
-        nm_filename: c_str!("lib.rs").as_ptr() as *const ::std::os::raw::c_char,
-        nm_register_func: Some(init_modules),
-        nm_modname: c_str!("rust_module").as_ptr() as *const ::std::os::raw::c_char,
-        nm_priv: ptr::null_mut(),
-        reserved: [
-            ptr::null_mut(),
-            ptr::null_mut(),
-            ptr::null_mut(),
-            ptr::null_mut(),
-        ],
-    };
-
-    unsafe {
-        napi_module_register(ptr::addr_of_mut!(_MODULE));
-    }
+/// module entry point, Node.js calls it for every env loading the addon such as worker threads
+#[no_mangle]
+pub extern "C" fn napi_register_module_v1(env: napi_env, exports: napi_value) -> napi_value {
+    init_modules(env, exports)
+}
+
+/// N-API version addon was built for
+#[no_mangle]
+pub extern "C" fn node_api_module_get_api_version_v1() -> i32 {
+    crate::sys::NAPI_VERSION as i32
 }
@@ -0,0 +1,217 @@
+use std::marker::PhantomData;
+
+use tracing::error;
+
+use crate::TryIntoJs;
+use crate::JSValue;
+use crate::sys::{napi_value, napi_ref};
+use crate::val::JsEnv;
+use crate::NjError;
+
+/// Persistent reference to JS value, keeping it alive across calls.
+///
+/// `T` is handle type such as `JsObject`, `JsArray` or `JsCallbackFunction` which value is converted to
+/// when read back with [`JsRef::get`].
+/// Reference starts with count 1, value can be collected when count is decreased to 0.
+/// Reference is deleted when dropped, so it must be dropped on main JS thread.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::{JsEnv, JsObject};
+/// use node_bindgen::core::reference::JsRef;
+///
+/// struct Store {
+///     config: Option<JsRef<JsObject>>,
+/// }
+///
+/// #[node_bindgen]
+/// impl Store {
+///     #[node_bindgen(constructor)]
+///     fn new() -> Self {
+///         Self { config: None }
+///     }
+///
+///     #[node_bindgen]
+///     fn set_config(&mut self, config: JsRef<JsObject>) {
+///         self.config = Some(config);
+///     }
+///
+///     #[node_bindgen]
+///     fn config(&self, env: JsEnv) -> Result<Option<JsObject>, NjError> {
+///         self.config.as_ref().map(|config| config.get(&env)).transpose()
+///     }
+/// }
+/// ```
+pub struct JsRef<T> {
+    env: JsEnv,
+    napi_ref: napi_ref,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> JsRef<T>
+where
+    T: for<'a> JSValue<'a>,
+{
+    /// create reference with count 1, value must be convertible to `T`
+    pub fn new(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        T::convert_to_rust(env, js_value)?;
+        Self::with_count(env, js_value, 1)
+    }
+
+    /// get referenced value, error if it was garbage collected
+    pub fn get(&self, env: &JsEnv) -> Result<T, NjError> {
+        T::convert_to_rust(env, self.napi_value(env)?)
+    }
+
+    /// create weak reference to same value
+    pub fn downgrade(&self, env: &JsEnv) -> Result<JsWeak<T>, NjError> {
+        JsWeak::new(env, self.napi_value(env)?)
+    }
+}
+
+impl<T> JsRef<T> {
+    pub(crate) fn with_count(
+        env: &JsEnv,
+        js_value: napi_value,
+        count: u32,
+    ) -> Result<Self, NjError> {
+        Ok(Self {
+            env: *env,
+            napi_ref: env.create_reference(js_value, count)?,
+            _marker: PhantomData,
+        })
+    }
+
+    /// get referenced value without conversion, error if it was garbage collected
+    pub fn napi_value(&self, env: &JsEnv) -> Result<napi_value, NjError> {
+        let value = env.get_reference_value(self.napi_ref)?;
+        if value.is_null() {
+            Err(NjError::Other(
+                "referenced value was garbage collected".to_owned(),
+            ))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// increase reference count, return new count
+    pub fn inc_ref(&self) -> Result<u32, NjError> {
+        self.env.reference_ref(self.napi_ref)
+    }
+
+    /// decrease reference count, return new count.
+    /// when count is 0, reference is weak and value can be garbage collected
+    pub fn dec_ref(&self) -> Result<u32, NjError> {
+        self.env.reference_unref(self.napi_ref)
+    }
+}
+
+impl<T> JSValue<'_> for JsRef<T>
+where
+    T: for<'a> JSValue<'a>,
+{
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Self::new(env, js_value)
+    }
+}
+
+impl<T> TryIntoJs for JsRef<T> {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        self.napi_value(js_env)
+    }
+}
+
+impl<T> TryIntoJs for &JsRef<T> {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        self.napi_value(js_env)
+    }
+}
+
+impl<T> Drop for JsRef<T> {
+    fn drop(&mut self) {
+        if let Err(err) = self.env.delete_reference(self.napi_ref) {
+            error!("reference can't be deleted: {}", err);
+        }
+    }
+}
+
+/// Weak reference to JS object or function, which doesn't prevent it from being garbage collected.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::collections::HashMap;
+///
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::{JsEnv, JsObject};
+/// use node_bindgen::core::reference::JsWeak;
+///
+/// struct Cache {
+///     wrappers: HashMap<u32, JsWeak<JsObject>>,
+/// }
+///
+/// #[node_bindgen]
+/// impl Cache {
+///     #[node_bindgen(constructor)]
+///     fn new() -> Self {
+///         Self { wrappers: HashMap::new() }
+///     }
+///
+///     #[node_bindgen]
+///     fn insert(&mut self, id: u32, wrapper: JsWeak<JsObject>) {
+///         self.wrappers.insert(id, wrapper);
+///     }
+///
+///     #[node_bindgen]
+///     fn lookup(&self, id: u32, env: JsEnv) -> Result<Option<JsObject>, NjError> {
+///         match self.wrappers.get(&id) {
+///             Some(wrapper) => wrapper.upgrade(&env),
+///             None => Ok(None),
+///         }
+///     }
+/// }
+/// ```
+pub struct JsWeak<T> {
+    reference: JsRef<T>,
+}
+
+impl<T> JsWeak<T>
+where
+    T: for<'a> JSValue<'a>,
+{
+    /// create reference with count 0, value must be convertible to `T`
+    pub fn new(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        T::convert_to_rust(env, js_value)?;
+        Ok(Self {
+            reference: JsRef::with_count(env, js_value, 0)?,
+        })
+    }
+
+    /// get value, `None` if it was garbage collected
+    pub fn upgrade(&self, env: &JsEnv) -> Result<Option<T>, NjError> {
+        let value = env.get_reference_value(self.reference.napi_ref)?;
+        if value.is_null() {
+            Ok(None)
+        } else {
+            T::convert_to_rust(env, value).map(Some)
+        }
+    }
+
+    /// check if value was garbage collected
+    pub fn is_collected(&self, env: &JsEnv) -> Result<bool, NjError> {
+        Ok(env.get_reference_value(self.reference.napi_ref)?.is_null())
+    }
+}
+
+impl<T> JSValue<'_> for JsWeak<T>
+where
+    T: for<'a> JSValue<'a>,
+{
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Self::new(env, js_value)
+    }
+}
@@ -1,5 +1,3 @@
-use std::ptr;
-
 use crate::TryIntoJs;
 use crate::sys::napi_value;
 use crate::val::JsEnv;
@@ -48,15 +46,6 @@ impl SafeArrayBuffer {
 
 impl TryIntoJs for SafeArrayBuffer {
     fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
-        let len = self.data.len();
-        let mut napi_buffer = ptr::null_mut();
-        crate::napi_call_result!(crate::sys::napi_create_buffer_copy(
-            js_env.inner(),
-            len,
-            self.data.as_ptr() as *const std::ffi::c_void,
-            std::ptr::null_mut(),
-            &mut napi_buffer,
-        ))?;
-        Ok(napi_buffer)
+        js_env.create_buffer_copy(&self.data)
     }
 }
@@ -0,0 +1,872 @@
+//! Conversion between Rust values and JS values using [serde](https://serde.rs).
+//!
+//! Values are converted directly from and to napi values without
+//! going through an intermediate JSON representation.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use serde::{Serialize, Deserialize};
+//!
+//! use node_bindgen::derive::node_bindgen;
+//! use node_bindgen::core::NjError;
+//! use node_bindgen::core::val::JsObject;
+//! use node_bindgen::core::serde::{to_js, from_js};
+//! use node_bindgen::sys::napi_value;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     name: String,
+//!     retries: Option<u32>,
+//! }
+//!
+//! #[node_bindgen]
+//! fn configure(config: JsObject) -> Result<napi_value, NjError> {
+//!     let env = config.env();
+//!     let config: Config = from_js(env, config.napi_value())?;
+//!     to_js(env, &config)
+//! }
+//! ```
+
+use std::fmt::Display;
+use std::ptr;
+
+use ::serde::ser;
+use ::serde::de;
+use ::serde::Serialize;
+use ::serde::de::DeserializeOwned;
+use ::serde::de::IntoDeserializer;
+use ::serde::forward_to_deserialize_any;
+
+use crate::TryIntoJs;
+use crate::bigint::BigInt;
+use crate::sys::napi_value;
+use crate::val::JsEnv;
+use crate::NjError;
+use crate::napi_call_result;
+
+/// convert rust value into js value using serde
+pub fn to_js<T>(env: &JsEnv, value: &T) -> Result<napi_value, NjError>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(JsSerializer { env })
+}
+
+/// convert js value into rust value using serde
+pub fn from_js<T>(env: &JsEnv, js_value: napi_value) -> Result<T, NjError>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(JsDeserializer::new(env, js_value))
+}
+
+impl ser::Error for NjError {
+    fn custom<T: Display>(msg: T) -> Self {
+        NjError::Other(msg.to_string())
+    }
+}
+
+impl de::Error for NjError {
+    fn custom<T: Display>(msg: T) -> Self {
+        NjError::Other(msg.to_string())
+    }
+}
+
+/// wrap value into object with single key, used for enum variants
+fn wrap_variant(env: &JsEnv, variant: &str, value: napi_value) -> Result<napi_value, NjError> {
+    let object = env.create_object()?;
+    env.set_property(object, env.create_string_utf8(variant)?, value)?;
+    Ok(object)
+}
+
+/// Serializer which produces napi values
+pub struct JsSerializer<'a> {
+    env: &'a JsEnv,
+}
+
+impl<'a> JsSerializer<'a> {
+    pub fn new(env: &'a JsEnv) -> Self {
+        Self { env }
+    }
+}
+
+impl<'a> ser::Serializer for JsSerializer<'a> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    type SerializeSeq = ArraySerializer<'a>;
+    type SerializeTuple = ArraySerializer<'a>;
+    type SerializeTupleStruct = ArraySerializer<'a>;
+    type SerializeTupleVariant = VariantSerializer<'a, ArraySerializer<'a>>;
+    type SerializeMap = ObjectSerializer<'a>;
+    type SerializeStruct = ObjectSerializer<'a>;
+    type SerializeStructVariant = VariantSerializer<'a, ObjectSerializer<'a>>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        self.env.create_boolean(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        self.env.create_int32(v as i32)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        self.env.create_int32(v as i32)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        self.env.create_int32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        v.try_to_js(self.env)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Self::Ok, Self::Error> {
+        v.try_to_js(self.env)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        self.env.create_uint32(v as u32)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        self.env.create_uint32(v as u32)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        self.env.create_uint32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        self.env.create_bigint_uint64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Self::Ok, Self::Error> {
+        v.try_to_js(self.env)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        self.env.create_double(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        self.env.create_double(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        self.env.create_string_utf8(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        self.env.create_string_utf8(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        let mut napi_buffer = ptr::null_mut();
+        napi_call_result!(crate::sys::napi_create_buffer_copy(
+            self.env.inner(),
+            v.len(),
+            v.as_ptr() as *const std::ffi::c_void,
+            ptr::null_mut(),
+            &mut napi_buffer,
+        ))?;
+        Ok(napi_buffer)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.env.get_null()
+    }
+
+    fn serialize_some<T>(self, value: &T) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.env.get_null()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        self.env.get_null()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.env.create_string_utf8(variant)
+    }
+
+    fn serialize_newtype_struct<T>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let env = self.env;
+        let js_value = value.serialize(self)?;
+        wrap_variant(env, variant, js_value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        ArraySerializer::new(self.env, len.unwrap_or(0))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        ArraySerializer::new(self.env, len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        ArraySerializer::new(self.env, len)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Ok(VariantSerializer {
+            env: self.env,
+            variant,
+            inner: ArraySerializer::new(self.env, len)?,
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        ObjectSerializer::new(self.env)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        ObjectSerializer::new(self.env)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(VariantSerializer {
+            env: self.env,
+            variant,
+            inner: ObjectSerializer::new(self.env)?,
+        })
+    }
+}
+
+/// serialize sequences and tuples into js array
+pub struct ArraySerializer<'a> {
+    env: &'a JsEnv,
+    array: napi_value,
+    index: usize,
+}
+
+impl<'a> ArraySerializer<'a> {
+    fn new(env: &'a JsEnv, len: usize) -> Result<Self, NjError> {
+        Ok(Self {
+            env,
+            array: env.create_array_with_len(len)?,
+            index: 0,
+        })
+    }
+
+    fn push<T>(&mut self, value: &T) -> Result<(), NjError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let js_value = value.serialize(JsSerializer::new(self.env))?;
+        self.env.set_element(self.array, js_value, self.index)?;
+        self.index += 1;
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for ArraySerializer<'_> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.array)
+    }
+}
+
+impl ser::SerializeTuple for ArraySerializer<'_> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_element<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.array)
+    }
+}
+
+impl ser::SerializeTupleStruct for ArraySerializer<'_> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.array)
+    }
+}
+
+/// serialize maps and structs into plain js object
+pub struct ObjectSerializer<'a> {
+    env: &'a JsEnv,
+    object: napi_value,
+    key: Option<napi_value>,
+}
+
+impl<'a> ObjectSerializer<'a> {
+    fn new(env: &'a JsEnv) -> Result<Self, NjError> {
+        Ok(Self {
+            env,
+            object: env.create_object()?,
+            key: None,
+        })
+    }
+
+    fn set_field<T>(&mut self, key: &str, value: &T) -> Result<(), NjError>
+    where
+        T: ?Sized + Serialize,
+    {
+        let js_value = value.serialize(JsSerializer::new(self.env))?;
+        self.env
+            .set_property(self.object, self.env.create_string_utf8(key)?, js_value)
+    }
+}
+
+impl ser::SerializeMap for ObjectSerializer<'_> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let js_key = key.serialize(JsSerializer::new(self.env))?;
+
+        // only keys that can be used as property name without losing information
+        let key_type = self.env.value_type(js_key)?;
+        if key_type != crate::sys::napi_valuetype_napi_string
+            && key_type != crate::sys::napi_valuetype_napi_number
+        {
+            return Err(NjError::InvalidType(
+                "string or number key".to_owned(),
+                self.env.value_type_string(js_key)?.to_owned(),
+            ));
+        }
+
+        self.key = Some(js_key);
+        Ok(())
+    }
+
+    fn serialize_value<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        let js_key = self
+            .key
+            .take()
+            .ok_or_else(|| NjError::Other("map value serialized before key".to_owned()))?;
+        let js_value = value.serialize(JsSerializer::new(self.env))?;
+        self.env.set_property(self.object, js_key, js_value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.object)
+    }
+}
+
+impl ser::SerializeStruct for ObjectSerializer<'_> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.set_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        Ok(self.object)
+    }
+}
+
+/// serialize tuple and struct variants as `{ variant: inner }`
+pub struct VariantSerializer<'a, S> {
+    env: &'a JsEnv,
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<'_, ArraySerializer<'_>> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_field<T>(&mut self, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        wrap_variant(self.env, self.variant, self.inner.array)
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<'_, ObjectSerializer<'_>> {
+    type Ok = napi_value;
+    type Error = NjError;
+
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), Self::Error>
+    where
+        T: ?Sized + Serialize,
+    {
+        self.inner.set_field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        wrap_variant(self.env, self.variant, self.inner.object)
+    }
+}
+
+/// Deserializer which reads from napi value
+pub struct JsDeserializer<'a> {
+    env: &'a JsEnv,
+    js_value: napi_value,
+}
+
+impl<'a> JsDeserializer<'a> {
+    pub fn new(env: &'a JsEnv, js_value: napi_value) -> Self {
+        Self { env, js_value }
+    }
+
+    fn value_type(&self) -> Result<crate::sys::napi_valuetype, NjError> {
+        self.env.value_type(self.js_value)
+    }
+}
+
+impl<'de> de::Deserializer<'de> for JsDeserializer<'_> {
+    type Error = NjError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.value_type()? {
+            crate::sys::napi_valuetype_napi_undefined | crate::sys::napi_valuetype_napi_null => {
+                visitor.visit_unit()
+            }
+            crate::sys::napi_valuetype_napi_boolean => {
+                visitor.visit_bool(self.env.convert_to_rust(self.js_value)?)
+            }
+            crate::sys::napi_valuetype_napi_number => {
+                let number: f64 = self.env.convert_to_rust(self.js_value)?;
+                // integral numbers are passed as integer so they can be read as any integer type
+                if number.fract() == 0.0 && number >= i64::MIN as f64 && number < i64::MAX as f64 {
+                    visitor.visit_i64(number as i64)
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            crate::sys::napi_valuetype_napi_bigint => {
+                let (signed, lossless) = self.env.get_value_bigint_int64(self.js_value)?;
+                if lossless {
+                    return visitor.visit_i64(signed);
+                }
+
+                let (unsigned, lossless) = self.env.get_value_bigint_uint64(self.js_value)?;
+                if lossless {
+                    return visitor.visit_u64(unsigned);
+                }
+
+                let value: BigInt = self.env.convert_to_rust(self.js_value)?;
+                if let Ok(signed) = i128::try_from(&value) {
+                    visitor.visit_i128(signed)
+                } else if let Ok(unsigned) = u128::try_from(&value) {
+                    visitor.visit_u128(unsigned)
+                } else {
+                    Err(NjError::OutOfRange("u128".to_owned(), value.to_string()))
+                }
+            }
+            crate::sys::napi_valuetype_napi_string => {
+                visitor.visit_string(self.env.convert_to_rust(self.js_value)?)
+            }
+            crate::sys::napi_valuetype_napi_object => {
+                if self.env.is_array(self.js_value)? {
+                    visitor.visit_seq(ArrayAccess::new(self.env, self.js_value)?)
+                } else if self.env.is_buffer(self.js_value)? {
+                    visitor.visit_bytes(self.env.get_buffer_info(self.js_value)?)
+                } else {
+                    visitor.visit_map(ObjectAccess::new(self.env, self.js_value)?)
+                }
+            }
+            _ => Err(NjError::InvalidType(
+                "serializable value".to_owned(),
+                self.env.value_type_string(self.js_value)?.to_owned(),
+            )),
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        if self.env.is_undefined_or_null(self.js_value)? {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        match self.value_type()? {
+            crate::sys::napi_valuetype_napi_string => {
+                let variant: String = self.env.convert_to_rust(self.js_value)?;
+                visitor.visit_enum(variant.into_deserializer())
+            }
+            crate::sys::napi_valuetype_napi_object => {
+                let keys = self.env.get_property_names(self.js_value)?;
+                if self.env.get_array_length(keys)? != 1 {
+                    return Err(NjError::Other(format!(
+                        "enum {name} must be object with single key"
+                    )));
+                }
+                let variant = self.env.get_element(keys, 0)?;
+                let value = self.env.get_property(self.js_value, variant)?;
+                visitor.visit_enum(VariantAccess {
+                    env: self.env,
+                    variant,
+                    value,
+                })
+            }
+            _ => Err(NjError::InvalidType(
+                "string or object".to_owned(),
+                self.env.value_type_string(self.js_value)?.to_owned(),
+            )),
+        }
+    }
+
+    fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct identifier
+    }
+}
+
+/// read elements of js array
+struct ArrayAccess<'a> {
+    env: &'a JsEnv,
+    array: napi_value,
+    index: u32,
+    len: u32,
+}
+
+impl<'a> ArrayAccess<'a> {
+    fn new(env: &'a JsEnv, array: napi_value) -> Result<Self, NjError> {
+        Ok(Self {
+            env,
+            array,
+            index: 0,
+            len: env.get_array_length(array)?,
+        })
+    }
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess<'_> {
+    type Error = NjError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        if self.index >= self.len {
+            return Ok(None);
+        }
+
+        let element = self.env.get_element(self.array, self.index)?;
+        self.index += 1;
+        seed.deserialize(JsDeserializer::new(self.env, element))
+            .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.len - self.index) as usize)
+    }
+}
+
+/// read enumerable properties of js object
+struct ObjectAccess<'a> {
+    env: &'a JsEnv,
+    object: napi_value,
+    keys: ArrayAccess<'a>,
+    key: Option<napi_value>,
+}
+
+impl<'a> ObjectAccess<'a> {
+    fn new(env: &'a JsEnv, object: napi_value) -> Result<Self, NjError> {
+        Ok(Self {
+            env,
+            object,
+            keys: ArrayAccess::new(env, env.get_property_names(object)?)?,
+            key: None,
+        })
+    }
+}
+
+impl<'de> de::MapAccess<'de> for ObjectAccess<'_> {
+    type Error = NjError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: de::DeserializeSeed<'de>,
+    {
+        if self.keys.index >= self.keys.len {
+            return Ok(None);
+        }
+
+        let key = self.env.get_element(self.keys.array, self.keys.index)?;
+        self.keys.index += 1;
+        self.key = Some(key);
+        seed.deserialize(MapKeyDeserializer { env: self.env, key })
+            .map(Some)
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| NjError::Other("map value read before key".to_owned()))?;
+        let value = self.env.get_property(self.object, key)?;
+        seed.deserialize(JsDeserializer::new(self.env, value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.keys.len - self.keys.index) as usize)
+    }
+}
+
+/// deserialize object key, property names are always strings so
+/// numeric keys written by `ObjectSerializer` are parsed back
+struct MapKeyDeserializer<'a> {
+    env: &'a JsEnv,
+    key: napi_value,
+}
+
+impl MapKeyDeserializer<'_> {
+    fn key_string(&self) -> Result<String, NjError> {
+        self.env.convert_to_rust(self.key)
+    }
+}
+
+macro_rules! deserialize_numeric_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+            where
+                V: de::Visitor<'de>,
+            {
+                let key = self.key_string()?;
+                match key.parse() {
+                    Ok(number) => visitor.$visit(number),
+                    Err(_) => Err(NjError::InvalidType("numeric key".to_owned(), key)),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for MapKeyDeserializer<'_> {
+    type Error = NjError;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_any(JsDeserializer::new(self.env, self.key), visitor)
+    }
+
+    deserialize_numeric_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_enum(
+            JsDeserializer::new(self.env, self.key),
+            name,
+            variants,
+            visitor,
+        )
+    }
+
+    forward_to_deserialize_any! {
+        bool char str string bytes byte_buf option unit unit_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// access enum variant represented as `{ variant: value }`
+struct VariantAccess<'a> {
+    env: &'a JsEnv,
+    variant: napi_value,
+    value: napi_value,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for VariantAccess<'a> {
+    type Error = NjError;
+    type Variant = JsDeserializer<'a>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Self::Error>
+    where
+        V: de::DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(JsDeserializer::new(self.env, self.variant))?;
+        Ok((variant, JsDeserializer::new(self.env, self.value)))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for JsDeserializer<'_> {
+    type Error = NjError;
+
+    fn unit_variant(self) -> Result<(), Self::Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Self::Error>
+    where
+        T: de::DeserializeSeed<'de>,
+    {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V>(self, _len: usize, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error>
+    where
+        V: de::Visitor<'de>,
+    {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
@@ -0,0 +1,167 @@
+use std::fmt;
+use std::ops::Deref;
+use std::string::FromUtf16Error;
+
+use crate::TryIntoJs;
+use crate::JSValue;
+use crate::sys::napi_value;
+use crate::val::JsEnv;
+use crate::NjError;
+
+/// JS string as UTF-16 code units, matching how JS stores strings.
+///
+/// Code units are passed as is, so unpaired surrogates are preserved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::string::Utf16String;
+///
+/// #[node_bindgen]
+/// fn reverse(value: Utf16String) -> Utf16String {
+///     value.iter().rev().copied().collect()
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Utf16String {
+    units: Vec<u16>,
+}
+
+impl Utf16String {
+    pub fn new(units: Vec<u16>) -> Self {
+        Self { units }
+    }
+
+    pub fn as_units(&self) -> &[u16] {
+        &self.units
+    }
+
+    pub fn into_units(self) -> Vec<u16> {
+        self.units
+    }
+
+    /// decode into rust string, unpaired surrogates are replaced with replacement character
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(&self.units)
+    }
+}
+
+impl From<Vec<u16>> for Utf16String {
+    fn from(units: Vec<u16>) -> Self {
+        Self::new(units)
+    }
+}
+
+impl From<&str> for Utf16String {
+    fn from(value: &str) -> Self {
+        value.encode_utf16().collect()
+    }
+}
+
+impl FromIterator<u16> for Utf16String {
+    fn from_iter<I: IntoIterator<Item = u16>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl TryFrom<Utf16String> for String {
+    type Error = FromUtf16Error;
+
+    fn try_from(value: Utf16String) -> Result<Self, Self::Error> {
+        String::from_utf16(&value.units)
+    }
+}
+
+impl Deref for Utf16String {
+    type Target = [u16];
+
+    fn deref(&self) -> &Self::Target {
+        &self.units
+    }
+}
+
+impl TryIntoJs for Utf16String {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_string_utf16(&self.units)
+    }
+}
+
+impl JSValue<'_> for Utf16String {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        env.get_value_string_utf16(js_value).map(Self::new)
+    }
+}
+
+/// JS string as ISO-8859-1 (Latin-1) bytes, one byte per character.
+///
+/// When converting from JS, strings with characters above `U+00FF` are rejected.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Latin1String {
+    bytes: Vec<u8>,
+}
+
+impl Latin1String {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl From<Vec<u8>> for Latin1String {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl TryFrom<&str> for Latin1String {
+    type Error = NjError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value
+            .chars()
+            .map(|c| {
+                u8::try_from(c).map_err(|_| {
+                    NjError::Other(format!("character {c:?} can't be encoded as Latin-1"))
+                })
+            })
+            .collect::<Result<Vec<u8>, NjError>>()
+            .map(Self::new)
+    }
+}
+
+/// decode Latin-1 bytes, every byte maps to the same unicode code point
+impl fmt::Display for Latin1String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.bytes
+            .iter()
+            .try_for_each(|byte| fmt::Write::write_char(f, char::from(*byte)))
+    }
+}
+
+impl Deref for Latin1String {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.bytes
+    }
+}
+
+impl TryIntoJs for Latin1String {
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        js_env.create_string_latin1(&self.bytes)
+    }
+}
+
+impl JSValue<'_> for Latin1String {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        env.get_value_string_latin1(js_value).map(Self::new)
+    }
+}
@@ -0,0 +1,125 @@
+use crate::TryIntoJs;
+use crate::JSValue;
+use crate::sys::napi_value;
+use crate::val::{JsEnv, JsObject, PropertyKey};
+use crate::reference::JsRef;
+use crate::NjError;
+
+/// JS [Symbol](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol)
+///
+/// Symbol is kept alive with persistent reference, so it can be stored and used across calls.
+/// It can be used as property key of `JsObject`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::NjError;
+/// use node_bindgen::core::val::JsObject;
+/// use node_bindgen::core::symbol::JsSymbol;
+///
+/// #[node_bindgen]
+/// fn is_iterable(object: JsObject) -> Result<bool, NjError> {
+///     let iterator = JsSymbol::iterator(object.env())?;
+///     object.has_property(&iterator)
+/// }
+/// ```
+pub struct JsSymbol {
+    env: JsEnv,
+    reference: JsRef<napi_value>,
+}
+
+unsafe impl Send for JsSymbol {}
+
+impl JsSymbol {
+    /// create new unique symbol, same as `Symbol(description)`
+    pub fn new(env: &JsEnv, description: Option<&str>) -> Result<Self, NjError> {
+        let symbol = env.create_symbol(description)?;
+        Self::convert_to_rust(env, symbol)
+    }
+
+    /// `Symbol.iterator`
+    pub fn iterator(env: &JsEnv) -> Result<Self, NjError> {
+        Self::well_known(env, "iterator")
+    }
+
+    /// `Symbol.asyncIterator`
+    pub fn async_iterator(env: &JsEnv) -> Result<Self, NjError> {
+        Self::well_known(env, "asyncIterator")
+    }
+
+    /// `Symbol.dispose`, not available in older versions of Node.js
+    pub fn dispose(env: &JsEnv) -> Result<Self, NjError> {
+        Self::well_known(env, "dispose")
+    }
+
+    /// get well known symbol stored in global `Symbol`
+    fn well_known(env: &JsEnv, name: &str) -> Result<Self, NjError> {
+        let symbol = match JsObject::new(*env, env.get_global()?).get_property("Symbol")? {
+            Some(symbol_class) => symbol_class.get_property(name)?,
+            None => None,
+        };
+
+        match symbol {
+            Some(symbol)
+                if env.value_type(symbol.napi_value())?
+                    == crate::sys::napi_valuetype_napi_symbol =>
+            {
+                Self::convert_to_rust(env, symbol.napi_value())
+            }
+            _ => Err(NjError::Other(format!("Symbol.{name} is not supported"))),
+        }
+    }
+
+    pub fn env(&self) -> &JsEnv {
+        &self.env
+    }
+
+    /// get symbol value
+    pub fn napi_value(&self) -> Result<napi_value, NjError> {
+        self.reference.napi_value(&self.env)
+    }
+
+    /// description given when symbol was created
+    pub fn description(&self) -> Result<Option<String>, NjError> {
+        let description = self.env.get_property(
+            self.napi_value()?,
+            self.env.create_string_utf8("description")?,
+        )?;
+
+        if self.env.is_undefined_or_null(description)? {
+            Ok(None)
+        } else {
+            self.env.convert_to_rust(description).map(Some)
+        }
+    }
+}
+
+impl JSValue<'_> for JsSymbol {
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        env.assert_type(js_value, crate::sys::napi_valuetype_napi_symbol)?;
+
+        Ok(Self {
+            env: *env,
+            reference: JsRef::with_count(env, js_value, 1)?,
+        })
+    }
+}
+
+impl TryIntoJs for JsSymbol {
+    fn try_to_js(self, _js_env: &JsEnv) -> Result<napi_value, NjError> {
+        self.napi_value()
+    }
+}
+
+impl TryIntoJs for &JsSymbol {
+    fn try_to_js(self, _js_env: &JsEnv) -> Result<napi_value, NjError> {
+        self.napi_value()
+    }
+}
+
+impl PropertyKey for JsSymbol {
+    fn to_property_key(&self, _env: &JsEnv) -> Result<napi_value, NjError> {
+        self.napi_value()
+    }
+}
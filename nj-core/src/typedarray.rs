@@ -0,0 +1,259 @@
+use std::ptr;
+use std::mem;
+use std::ops::Deref;
+use std::fmt;
+use std::fmt::Debug;
+
+use tracing::trace;
+
+use crate::TryIntoJs;
+use crate::JSValue;
+use crate::sys::{napi_value, napi_env, napi_typedarray_type};
+use crate::val::JsEnv;
+use crate::NjError;
+
+/// Rust element type of JS [TypedArray](https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray)
+pub trait TypedArrayElement: Copy + 'static {
+    /// napi type of typed array holding this element
+    const ARRAY_TYPE: napi_typedarray_type;
+}
+
+macro_rules! impl_typed_array_element {
+    ($($ty:ty => $array_type:ident),+ $(,)?) => {
+        $(
+            impl TypedArrayElement for $ty {
+                const ARRAY_TYPE: napi_typedarray_type = crate::sys::$array_type;
+            }
+        )+
+    }
+}
+
+impl_typed_array_element! {
+    i8 => napi_typedarray_type_napi_int8_array,
+    u8 => napi_typedarray_type_napi_uint8_array,
+    i16 => napi_typedarray_type_napi_int16_array,
+    u16 => napi_typedarray_type_napi_uint16_array,
+    i32 => napi_typedarray_type_napi_int32_array,
+    u32 => napi_typedarray_type_napi_uint32_array,
+    f32 => napi_typedarray_type_napi_float32_array,
+    f64 => napi_typedarray_type_napi_float64_array,
+    i64 => napi_typedarray_type_napi_bigint64_array,
+    u64 => napi_typedarray_type_napi_biguint64_array,
+}
+
+/// get JS class name of typed array type
+pub fn typedarray_type_to_string(array_type: napi_typedarray_type) -> &'static str {
+    match array_type {
+        crate::sys::napi_typedarray_type_napi_int8_array => "Int8Array",
+        crate::sys::napi_typedarray_type_napi_uint8_array => "Uint8Array",
+        crate::sys::napi_typedarray_type_napi_uint8_clamped_array => "Uint8ClampedArray",
+        crate::sys::napi_typedarray_type_napi_int16_array => "Int16Array",
+        crate::sys::napi_typedarray_type_napi_uint16_array => "Uint16Array",
+        crate::sys::napi_typedarray_type_napi_int32_array => "Int32Array",
+        crate::sys::napi_typedarray_type_napi_uint32_array => "Uint32Array",
+        crate::sys::napi_typedarray_type_napi_float32_array => "Float32Array",
+        crate::sys::napi_typedarray_type_napi_float64_array => "Float64Array",
+        crate::sys::napi_typedarray_type_napi_bigint64_array => "BigInt64Array",
+        crate::sys::napi_typedarray_type_napi_biguint64_array => "BigUint64Array",
+        _ => "other",
+    }
+}
+
+/// borrow content of typed array as slice,
+/// element type of typed array must match exactly
+pub fn typedarray_slice<T>(env: &JsEnv, js_value: napi_value) -> Result<&[T], NjError>
+where
+    T: TypedArrayElement,
+{
+    let expected = typedarray_type_to_string(T::ARRAY_TYPE);
+
+    if !env.is_typedarray(js_value)? {
+        return Err(NjError::InvalidType(
+            expected.to_owned(),
+            env.value_type_string(js_value)?.to_owned(),
+        ));
+    }
+
+    let (array_type, length, data) = env.get_typedarray_info(js_value)?;
+    if array_type != T::ARRAY_TYPE {
+        return Err(NjError::InvalidType(
+            expected.to_owned(),
+            typedarray_type_to_string(array_type).to_owned(),
+        ));
+    }
+
+    // data can be null for empty array
+    if length == 0 {
+        return Ok(&[]);
+    }
+
+    if !(data as *const T).is_aligned() {
+        return Err(NjError::Other(format!(
+            "{expected} data is not aligned to {} bytes",
+            mem::align_of::<T>()
+        )));
+    }
+
+    Ok(unsafe { std::slice::from_raw_parts(data as *const T, length) })
+}
+
+macro_rules! impl_js_value_for_slice {
+    ($($ty:ty),+) => {
+        $(
+            impl<'a> JSValue<'a> for &'a [$ty] {
+                fn convert_to_rust(env: &'a JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+                    typedarray_slice(env, js_value)
+                }
+
+                fn viewed_bytes(&self) -> Option<(*const u8, usize)> {
+                    Some((self.as_ptr() as *const u8, mem::size_of_val(*self)))
+                }
+            }
+        )+
+    }
+}
+
+// &[u8] is converted from Buffer, see buffer module
+impl_js_value_for_slice!(i8, i16, u16, i32, u32, f32, f64, i64, u64);
+
+/// pass rust vector as JS TypedArray without copying
+///
+/// # Examples
+///
+/// ```no_run
+/// use node_bindgen::derive::node_bindgen;
+/// use node_bindgen::core::typedarray::TypedArray;
+///
+/// #[node_bindgen]
+/// fn scale(values: &[f64], factor: f64) -> TypedArray<f64> {
+///     TypedArray::new(values.iter().map(|v| v * factor).collect())
+/// }
+/// ```
+pub struct TypedArray<T> {
+    data: Vec<T>,
+}
+
+impl<T> Debug for TypedArray<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "TypedArray<{}> len: {}",
+            std::any::type_name::<T>(),
+            self.data.len()
+        ))
+    }
+}
+
+impl<T> TypedArray<T>
+where
+    T: TypedArrayElement,
+{
+    pub fn new(data: Vec<T>) -> Self {
+        Self { data }
+    }
+
+    pub fn into_inner(self) -> Vec<T> {
+        self.data
+    }
+
+    extern "C" fn finalize_buffer(
+        _env: napi_env,
+        _finalize_data: *mut ::std::os::raw::c_void,
+        finalize_hint: *mut ::std::os::raw::c_void,
+    ) {
+        trace!("finalize typed array");
+        unsafe {
+            // use hint to reconstruct box instead of finalize data
+            let ptr: *mut Vec<T> = finalize_hint as *mut Vec<T>;
+            let _rust = Box::from_raw(ptr);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for TypedArray<T>
+where
+    T: TypedArrayElement,
+{
+    fn from(data: Vec<T>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> Deref for TypedArray<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T> TryIntoJs for TypedArray<T>
+where
+    T: TypedArrayElement,
+{
+    fn try_to_js(self, js_env: &JsEnv) -> Result<napi_value, NjError> {
+        let len = self.data.len();
+        let byte_len = len * mem::size_of::<T>();
+
+        let box_data = Box::new(self.data);
+
+        let mut napi_buffer = ptr::null_mut();
+
+        // get pointer to vec's buffer
+        let data_buffer = box_data.as_ptr();
+
+        // get raw pointer to box, this will be used to reconstruct box
+        let data_box_ptr = Box::into_raw(box_data);
+
+        // status is checked manually since external buffers may not be allowed
+        let status = unsafe {
+            crate::sys::napi_create_external_arraybuffer(
+                js_env.inner(),
+                data_buffer as *mut core::ffi::c_void,
+                byte_len,
+                Some(Self::finalize_buffer),
+                data_box_ptr as *mut core::ffi::c_void,
+                &mut napi_buffer,
+            )
+        };
+
+        let napi_buffer = match status {
+            crate::sys::napi_status_napi_ok => napi_buffer,
+            crate::sys::napi_status_napi_no_external_buffers_allowed => {
+                trace!("external buffers not allowed, copying typed array");
+                // finalizer is not registered, so we still own data
+                let data = unsafe { Box::from_raw(data_box_ptr) };
+                let bytes =
+                    unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, byte_len) };
+                js_env.create_arraybuffer_copy(bytes)?
+            }
+            _ => {
+                // finalizer is not registered, so we still own data
+                drop(unsafe { Box::from_raw(data_box_ptr) });
+                return Err(NjError::NapiCall(status.into()));
+            }
+        };
+
+        let mut napi_typed_array = ptr::null_mut();
+
+        crate::napi_call_result!(crate::sys::napi_create_typedarray(
+            js_env.inner(),
+            T::ARRAY_TYPE,
+            len,
+            napi_buffer,
+            0,
+            &mut napi_typed_array
+        ))?;
+
+        Ok(napi_typed_array)
+    }
+}
+
+/// copy content of JS TypedArray
+impl<T> JSValue<'_> for TypedArray<T>
+where
+    T: TypedArrayElement,
+{
+    fn convert_to_rust(env: &JsEnv, js_value: napi_value) -> Result<Self, NjError> {
+        Ok(Self::new(typedarray_slice::<T>(env, js_value)?.to_vec()))
+    }
+}
@@ -91,16 +91,18 @@ extern "C" fn promise_complete<O>(
         let worker_result: Box<WorkerResult<O>> =
             unsafe { Box::from_raw(data as *mut WorkerResult<O>) };
 
-        let result: Result<(), NjError> = match worker_result.result.try_to_js(&js_env) {
-            Ok(val) => {
-                trace!("trying to resolve to deferred");
-                js_env.resolve_deferred(worker_result.deferred.0, val)
+        let result: Result<(), NjError> = js_env.open_handle_scope().and_then(|_scope| {
+            match worker_result.result.try_to_js(&js_env) {
+                Ok(val) => {
+                    trace!("trying to resolve to deferred");
+                    js_env.resolve_deferred(worker_result.deferred.0, val)
+                }
+                Err(js_err) => {
+                    trace!("trying to resolve to deferred");
+                    js_env.reject_deferred(worker_result.deferred.0, js_err.as_js(&js_env))
+                }
             }
-            Err(js_err) => {
-                trace!("trying to resolve to deferred");
-                js_env.reject_deferred(worker_result.deferred.0, js_err.as_js(&js_env))
-            }
-        };
+        });
         assert_napi!(result)
     }
 }
@@ -161,7 +161,7 @@ impl<'a> MyDeriveInput<'a> {
 #[derive(Debug)]
 pub enum MyFields<'a> {
     Named(Vec<MyNamedField<'a>>),
-    Unnamed(Vec<MyUnnamedField>),
+    Unnamed(Vec<MyUnnamedField<'a>>),
     Unit,
 }
 
@@ -169,11 +169,13 @@ pub enum MyFields<'a> {
 pub struct MyNamedField<'a> {
     pub name: &'a Ident,
     pub ty: MyFieldType,
+    pub field_ty: &'a Type,
 }
 
 #[derive(Debug)]
-pub struct MyUnnamedField {
+pub struct MyUnnamedField<'a> {
     pub ty: MyFieldType,
+    pub field_ty: &'a Type,
 }
 
 #[derive(Debug)]
@@ -190,8 +192,12 @@ impl<'a> MyFields<'a> {
                     .named
                     .iter()
                     .filter_map(|field| field.ident.as_ref().map(|ident| (ident, &field.ty)))
-                    .map(|(ident, ty)| {
-                        MyFieldType::from(ty).map(|ty| MyNamedField { name: ident, ty })
+                    .map(|(ident, field_ty)| {
+                        MyFieldType::from(field_ty).map(|ty| MyNamedField {
+                            name: ident,
+                            ty,
+                            field_ty,
+                        })
                     })
                     .collect::<Result<Vec<MyNamedField<'a>>>>()?;
 
@@ -201,11 +207,13 @@ impl<'a> MyFields<'a> {
                 let fields = unnamed_fields
                     .unnamed
                     .iter()
-                    .map(|field| MyFieldType::from(&field.ty))
-                    .collect::<Result<Vec<MyFieldType>>>()?
-                    .into_iter()
-                    .map(|ty| MyUnnamedField { ty })
-                    .collect::<Vec<MyUnnamedField>>();
+                    .map(|field| {
+                        MyFieldType::from(&field.ty).map(|ty| MyUnnamedField {
+                            ty,
+                            field_ty: &field.ty,
+                        })
+                    })
+                    .collect::<Result<Vec<MyUnnamedField<'a>>>>()?;
 
                 Ok(MyFields::Unnamed(fields))
             }
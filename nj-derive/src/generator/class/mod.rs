@@ -58,27 +58,13 @@ fn generate_class_helper(class: Class) -> TokenStream {
 
         mod #helper_module_name {
 
-            use std::ptr;
             use node_bindgen::core::JSClass;
 
             use super::#type_name;
 
-            static mut CLASS_CONSTRUCTOR: node_bindgen::sys::napi_ref = ptr::null_mut();
-
             impl node_bindgen::core::JSClass for #impl_for_block {
                 const CLASS_NAME: &'static str = #class_type_lit;
 
-                fn set_constructor(constructor: node_bindgen::sys::napi_ref) {
-                    node_bindgen::core::log::trace!("set constructor");
-                    unsafe {
-                        CLASS_CONSTRUCTOR = constructor;
-                    }
-                }
-
-                fn get_constructor() -> node_bindgen::sys::napi_ref {
-                    unsafe { CLASS_CONSTRUCTOR }
-                }
-
                 fn properties() -> node_bindgen::core::PropertiesBuilder {
 
                     vec![
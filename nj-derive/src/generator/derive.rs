@@ -3,6 +3,10 @@ use proc_macro2::TokenStream;
 use syn::DeriveInput;
 use syn::Ident;
 use syn::Index;
+use syn::Type;
+use syn::GenericArgument;
+use syn::PathArguments;
+use syn::WherePredicate;
 use syn::GenericParam;
 use syn::TypeParam;
 use syn::LifetimeDef;
@@ -27,10 +31,13 @@ pub fn generate_datatype(input_data: DeriveInput) -> TokenStream {
         Err(err) => err.to_compile_error(),
         Ok(parsed_data) => {
             let try_into_js = generate_try_into_js(&parsed_data);
+            let js_value = generate_js_value(&parsed_data);
             quote! {
                 #input_data
 
                 #try_into_js
+
+                #js_value
             }
         }
     }
@@ -244,6 +251,311 @@ fn generate_variant_conversion(
     }
 }
 
+fn generate_js_value(parsed_data: &MyDeriveInput) -> TokenStream {
+    let name = parsed_data.name;
+    let js_env = format_ident!("js_env");
+    let js_value = format_ident!("js_value");
+
+    let field_types = match &parsed_data.payload {
+        MyDerivePayload::Struct(struct_data) => collect_field_types(&struct_data.fields),
+        MyDerivePayload::Enum(enum_data) => enum_data
+            .variants
+            .iter()
+            .flat_map(|variant| collect_field_types(&variant.fields))
+            .collect(),
+    };
+
+    let impl_signature =
+        generate_js_value_impl_signature(name, &parsed_data.generics, &field_types);
+
+    let conversion = match &parsed_data.payload {
+        MyDerivePayload::Struct(struct_data) => {
+            generate_struct_js_value(name, &js_env, &js_value, struct_data)
+        }
+        MyDerivePayload::Enum(enum_data) => {
+            generate_enum_js_value(name, &js_env, &js_value, enum_data)
+        }
+    };
+
+    quote! {
+        #impl_signature {
+            #[allow(clippy::not_unsafe_ptr_arg_deref)]
+            fn convert_to_rust(#js_env: &'__js node_bindgen::core::val::JsEnv,
+                #js_value: node_bindgen::core::sys::napi_value) ->
+                Result<Self, node_bindgen::core::NjError>
+            {
+                use node_bindgen::core::{
+                    JSValue,
+                    NjError,
+                    val::JsObject
+                };
+
+                #conversion
+            }
+        }
+    }
+}
+
+fn generate_struct_js_value(
+    name: &Ident,
+    js_env: &Ident,
+    js_value: &Ident,
+    struct_data: &MyStruct,
+) -> TokenStream {
+    let constructor = quote! { #name };
+    let type_name = name.to_string();
+
+    generate_fields_js_value(
+        &constructor,
+        &type_name,
+        js_env,
+        js_value,
+        &struct_data.fields,
+    )
+}
+
+fn generate_enum_js_value(
+    enum_name: &Ident,
+    js_env: &Ident,
+    js_value: &Ident,
+    enum_data: &MyEnum,
+) -> TokenStream {
+    let type_name = enum_name.to_string();
+
+    // unit variants are represented as a plain string
+    let unit_variants = enum_data
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, MyFields::Unit))
+        .map(|variant| {
+            let variant_name = variant.name;
+            let variant_name_pascalcase = format!("{}", variant.name).to_pascal_case();
+
+            quote! {
+                #variant_name_pascalcase => Ok(#enum_name::#variant_name),
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    // other variants are objects with a single key matching the variant name
+    let input_obj = format_ident!("input_obj");
+    let variant_value = format_ident!("variant_value");
+    let field_variants = enum_data
+        .variants
+        .iter()
+        .filter(|variant| !matches!(variant.fields, MyFields::Unit))
+        .map(|variant| {
+            let variant_name = variant.name;
+            let variant_name_camelcase = format!("{}", variant.name).to_camel_case();
+            let constructor = quote! { #enum_name::#variant_name };
+            let variant_type_name = format!("{enum_name}::{variant_name}");
+            let conversion = generate_fields_js_value(
+                &constructor,
+                &variant_type_name,
+                js_env,
+                &variant_value,
+                &variant.fields,
+            );
+
+            quote! {
+                if let Some(#variant_value) = #input_obj.get_property(#variant_name_camelcase)? {
+                    let #variant_value = #variant_value.napi_value();
+                    return { #conversion };
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let object_conversion = if field_variants.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let #input_obj = JsObject::convert_to_rust(#js_env, #js_value)?;
+
+            #(#field_variants)*
+        }
+    };
+
+    quote! {
+        if #js_env.value_type(#js_value)? == node_bindgen::core::sys::napi_valuetype_napi_string {
+            let variant_name: String = #js_env.convert_to_rust(#js_value)?;
+            return match variant_name.as_str() {
+                #(#unit_variants)*
+                _ => Err(NjError::Other(format!(
+                    "unknown variant {} for {}", variant_name, #type_name
+                ))),
+            };
+        }
+
+        #object_conversion
+
+        Err(NjError::Other(format!("no matching variant found for {}", #type_name)))
+    }
+}
+
+// Conversion of struct or enum variant fields, evaluating to Result<Self, NjError>
+fn generate_fields_js_value(
+    constructor: &TokenStream,
+    type_name: &str,
+    js_env: &Ident,
+    js_value: &Ident,
+    fields: &MyFields,
+) -> TokenStream {
+    match fields {
+        MyFields::Named(named_fields) => {
+            let input_obj = format_ident!("input_obj");
+            let field_values = named_fields
+                .iter()
+                .map(|MyNamedField { name, field_ty, .. }| {
+                    let field_name = format!("{name}").to_camel_case();
+                    let property = format_ident!("property");
+                    let property_value = quote! { #property.napi_value() };
+                    let read_value = generate_read_value(js_env, field_ty, &property_value);
+
+                    let missing = if option_inner_type(field_ty).is_some() {
+                        quote! { None }
+                    } else {
+                        quote! {
+                            return Err(NjError::Other(format!(
+                                "missing property {} for {}", #field_name, #type_name
+                            )))
+                        }
+                    };
+
+                    quote! {
+                        #name: match #input_obj.get_property(#field_name)? {
+                            Some(#property) => #read_value,
+                            None => #missing,
+                        },
+                    }
+                })
+                .collect::<Vec<TokenStream>>();
+
+            quote! {
+                let #input_obj = JsObject::convert_to_rust(#js_env, #js_value)?;
+
+                Ok(#constructor {
+                    #(#field_values)*
+                })
+            }
+        }
+        MyFields::Unnamed(unnamed_fields) => {
+            let fields_count = unnamed_fields.len() as u32;
+            let field_values = unnamed_fields
+                .iter()
+                .enumerate()
+                .map(|(field_idx, MyUnnamedField { field_ty, .. })| {
+                    let index = field_idx as u32;
+                    let element_value = quote! { #js_env.get_element(#js_value, #index)? };
+                    generate_read_value(js_env, field_ty, &element_value)
+                })
+                .collect::<Vec<TokenStream>>();
+
+            quote! {
+                if !#js_env.is_array(#js_value)? {
+                    return Err(NjError::InvalidType(
+                        "array".to_owned(),
+                        #js_env.value_type_string(#js_value)?.to_owned()
+                    ));
+                }
+
+                let length = #js_env.get_array_length(#js_value)?;
+                if length != #fields_count {
+                    return Err(NjError::Other(format!(
+                        "{} must have exactly length {}", #type_name, #fields_count
+                    )));
+                }
+
+                Ok(#constructor(
+                    #(#field_values),*
+                ))
+            }
+        }
+        MyFields::Unit => quote! {
+            Ok(#constructor)
+        },
+    }
+}
+
+// Optional values accept null or undefined, everything else
+// is delegated to the JSValue implementation of the field type
+fn generate_read_value(js_env: &Ident, field_ty: &Type, napi_value: &TokenStream) -> TokenStream {
+    if option_inner_type(field_ty).is_some() {
+        quote! {{
+            let field_value = #napi_value;
+            if #js_env.is_undefined_or_null(field_value)? {
+                None
+            } else {
+                Some(#js_env.convert_to_rust(field_value)?)
+            }
+        }}
+    } else {
+        quote! {
+            #js_env.convert_to_rust(#napi_value)?
+        }
+    }
+}
+
+/// types that need to implement JSValue, Option is unwrapped since
+/// missing or null values are handled by generated code
+fn collect_field_types<'a>(fields: &MyFields<'a>) -> Vec<&'a Type> {
+    let field_types: Vec<&'a Type> = match fields {
+        MyFields::Named(named_fields) => named_fields.iter().map(|field| field.field_ty).collect(),
+        MyFields::Unnamed(unnamed_fields) => {
+            unnamed_fields.iter().map(|field| field.field_ty).collect()
+        }
+        MyFields::Unit => vec![],
+    };
+
+    field_types
+        .into_iter()
+        .map(|ty| option_inner_type(ty).unwrap_or(ty))
+        .collect()
+}
+
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let type_path = match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => type_path,
+        _ => return None,
+    };
+
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match args.args.first() {
+            Some(GenericArgument::Type(inner)) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Field types are added as bounds so that JSValue is only implemented
+// when every field can be converted from JS
+fn generate_js_value_impl_signature<'a>(
+    name: &'a Ident,
+    generics: &'a MyGenerics<'a>,
+    field_types: &[&Type],
+) -> TokenStream {
+    let generic_params = &generics.params;
+    let generics_no_bounds = drop_generic_bounds(&generics.params);
+    let where_predicates: Vec<&WherePredicate> = match generics.where_clause {
+        None => vec![],
+        Some(where_clause) => where_clause.predicates.iter().collect(),
+    };
+
+    quote! {
+        impl <'__js, #(#generic_params),*> node_bindgen::core::JSValue<'__js> for
+                #name<#(#generics_no_bounds),*>
+            where
+                #(#where_predicates,)*
+                #(#field_types: node_bindgen::core::JSValue<'__js>,)*
+    }
+}
+
 fn drop_generic_bounds(params: &[GenericParam]) -> Vec<GenericParam> {
     params
         .iter()
@@ -293,7 +605,7 @@ fn generate_named_field_conversions<'a>(
 ) -> Vec<TokenStream> {
     fields
         .iter()
-        .map(|MyNamedField { name, ty }| {
+        .map(|MyNamedField { name, ty, .. }| {
             let field_name = format!("{name}").to_camel_case();
 
             // References needs to be cloned for try_to_js
@@ -321,7 +633,7 @@ fn generate_unnamed_field_conversions(
     fields
         .iter()
         .enumerate()
-        .map(|(field_idx, MyUnnamedField { ty })| {
+        .map(|(field_idx, MyUnnamedField { ty, .. })| {
             let index = Index {
                 index: field_idx as u32,
                 span: output_array.span(),
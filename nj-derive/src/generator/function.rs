@@ -395,6 +395,7 @@ mod closure {
                     node_bindgen::core::log::debug!("async cb invoked");
                     let js_env = node_bindgen::core::val::JsEnv::new(env);
                     let result: Result<(), node_bindgen::core::NjError> = (move ||{
+                        let _scope = js_env.open_handle_scope()?;
                         let global = js_env.get_global()?;
                         let my_val: Box<#arg_struct_name> = unsafe { Box::from_raw(data as *mut #arg_struct_name) };
                         node_bindgen::core::log::trace!("arg: {:#?}",my_val);
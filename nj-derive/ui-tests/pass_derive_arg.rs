@@ -0,0 +1,46 @@
+use node_bindgen::derive::node_bindgen;
+use node_bindgen::core::JSValue;
+
+#[node_bindgen]
+struct Named {
+    pub name: String,
+    pub count: i32,
+    pub label: Option<String>,
+}
+
+#[node_bindgen]
+struct Unnamed(String, f64);
+
+#[node_bindgen]
+struct Generic<T: node_bindgen::core::TryIntoJs> {
+    pub field: T,
+}
+
+#[node_bindgen]
+enum Shape {
+    Circle(f64),
+    Rect { width: f64, height: f64 },
+    Empty,
+}
+
+#[node_bindgen]
+fn named(arg: Named) -> Named {
+    arg
+}
+
+#[node_bindgen]
+fn unnamed(arg: Unnamed) -> Unnamed {
+    arg
+}
+
+#[node_bindgen]
+fn shape(arg: Shape) -> Shape {
+    arg
+}
+
+fn assert_js_value<'a, T: JSValue<'a>>() {}
+
+fn main() {
+    assert_js_value::<Generic<String>>();
+    assert_js_value::<Vec<Named>>();
+}